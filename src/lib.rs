@@ -3,43 +3,138 @@
 // Should not be used for anything besides learning, if a real linked list is needed
 // use the unsafe version in the standard library (std::collections::LinkedList)
 
-use std::cell::RefCell;
-use std::rc::Rc;
+use std::cell::{Ref, RefCell, RefMut};
+use std::fmt;
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::ops::{Deref, DerefMut, Range};
+use std::rc::{Rc, Weak};
 
 /// Type alias for convenience
-type Link = Rc<RefCell<Node>>;
+type Link<T> = Rc<RefCell<Node<T>>>;
 
 /// Node holding data and two pointers
-#[derive(Clone)]
-struct Node {
-    value: i32,
-    prev: Option<Link>,
-    next: Option<Link>,
+struct Node<T> {
+    value: T,
+    prev: Option<Link<T>>,
+    next: Option<Link<T>>,
+    owner: Rc<Owner>,
+    // Set while a ValueMut holds the value, leaving T::default() behind in the node
+    moved_out: bool,
 }
 
 /// Doubly linked list
-pub struct List {
+pub struct List<T> {
     size: usize,
-    head: Option<Link>,
-    tail: Option<Link>,
+    head: Option<Link<T>>,
+    tail: Option<Link<T>>,
+    owner: Rc<Owner>,
 }
 
-impl List {
+// Appending relinks a whole chain without visiting its nodes, so instead of retagging them
+// the owner of the appended list is forwarded to the owner of the list it was appended to.
+// Lookups shorten the forwarding chains they walk like a union-find, so nested appends
+// don't make every later lookup walk the whole chain again
+/// Identity of a list, every node points at the owner of the list it is linked into
+struct Owner {
+    forward: RefCell<Option<Rc<Owner>>>,
+}
+
+impl Owner {
+    /// Create an owner for a new list, only used internally
+    fn new() -> Rc<Owner> {
+        Rc::new(Owner {
+            forward: RefCell::new(None),
+        })
+    }
+
+    /// Follow the forwarding pointers to the owner currently in use, only used internally
+    fn resolve(owner: &Rc<Owner>) -> Rc<Owner> {
+        let mut root = Rc::clone(owner);
+        loop {
+            let next = root.forward.borrow().clone();
+            match next {
+                Some(next) => root = next,
+                None => break,
+            }
+        }
+        // Point every owner on the way straight at the root, so the next lookup takes one step
+        let mut current = Rc::clone(owner);
+        while !Rc::ptr_eq(&current, &root) {
+            let next = current.forward.replace(Some(Rc::clone(&root)));
+            // Unwrapping here since every owner in front of the root forwards to another one
+            current = next.unwrap();
+        }
+        root
+    }
+}
+
+// Long forwarding chains would otherwise be dropped recursively
+impl Drop for Owner {
+    fn drop(&mut self) {
+        let mut next = self.forward.get_mut().take();
+        while let Some(owner) = next {
+            next = match Rc::try_unwrap(owner) {
+                Ok(mut owner) => owner.forward.get_mut().take(),
+                Err(_) => None,
+            };
+        }
+    }
+}
+
+/// Creates a list from its values, like `vec!`
+/// `list![a, b, c]` pushes the values in order, `list![x; n]` holds `n` clones of `x`
+#[macro_export]
+macro_rules! list {
+    () => {
+        $crate::List::new()
+    };
+    // Binding the value first keeps the evaluation order of vec!, value before length
+    ($value:expr; $len:expr) => {{
+        let value = $value;
+        $crate::List::filled($len, value)
+    }};
+    ($($value:expr),+ $(,)?) => {
+        <$crate::List<_> as ::core::iter::FromIterator<_>>::from_iter([$($value),+])
+    };
+}
+
+impl<T> List<T> {
     /// Creates a new empty LinkedList
-    pub fn new() -> List {
+    pub fn new() -> List<T> {
         List {
             size: 0,
             head: None,
             tail: None,
+            owner: Owner::new(),
         }
     }
 
-    /// Push a new value onto the back of the list
-    pub fn push_back(&mut self, value: i32) {
+    /// Creates a list holding `len` clones of `value`
+    pub fn filled(len: usize, value: T) -> List<T>
+    where
+        T: Clone,
+    {
+        let mut list = List::new();
+        list.resize(len, value);
+        list
+    }
+
+    /// Creates a list holding `len` values returned by calling `f` repeatedly
+    pub fn fill_with(len: usize, f: impl FnMut() -> T) -> List<T> {
+        let mut list = List::new();
+        list.push_back_iter(std::iter::repeat_with(f).take(len));
+        list
+    }
+
+    /// Push a new value onto the back of the list, returns a handle to its node
+    pub fn push_back(&mut self, value: T) -> NodeHandle<T> {
         let node = Node {
             value,
             prev: self.tail.clone(),
             next: None,
+            owner: Rc::clone(&self.owner),
+            moved_out: false,
         };
 
         // Shadowing node
@@ -50,7 +145,7 @@ impl List {
                 // Set prev->next to new node
                 prev_tail.borrow_mut().next = Some(Rc::clone(&node));
                 // Set new node->prev to prev
-                node.borrow_mut().prev = Some(Rc::clone(&prev_tail));
+                node.borrow_mut().prev = Some(Rc::clone(prev_tail));
                 // Update tail
                 self.tail = Some(Rc::clone(&node));
             }
@@ -60,107 +155,6961 @@ impl List {
             }
         }
         self.size += 1;
+        NodeHandle::from_link(&node)
+    }
+
+    /// Push a new value onto the front of the list, returns a handle to its node
+    pub fn push_front(&mut self, value: T) -> NodeHandle<T> {
+        let node = Node {
+            value,
+            prev: None,
+            next: self.head.clone(),
+            owner: Rc::clone(&self.owner),
+            moved_out: false,
+        };
+
+        // Shadowing node
+        let node = Rc::new(RefCell::new(node));
+
+        match self.head {
+            Some(ref prev_head) => {
+                // Set old head->prev to new node
+                prev_head.borrow_mut().prev = Some(Rc::clone(&node));
+                // Update head
+                self.head = Some(Rc::clone(&node));
+            }
+            None => {
+                self.head = Some(Rc::clone(&node));
+                self.tail = Some(Rc::clone(self.head.as_ref().unwrap()));
+            }
+        }
+        self.size += 1;
+        NodeHandle::from_link(&node)
+    }
+
+    /// Pop a value from the back of the list
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.tail.take().map(|old_tail| {
+            match old_tail.borrow_mut().prev.take() {
+                Some(new_tail) => {
+                    // Unlink new tail->next from the old tail
+                    new_tail.borrow_mut().next = None;
+                    self.tail = Some(new_tail);
+                }
+                None => {
+                    // Removing the only node, drop the head reference as well
+                    self.head = None;
+                }
+            }
+            self.size -= 1;
+            Self::into_value(old_tail)
+        })
+    }
+
+    /// Pop a value from the front of the list
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.head.take().map(|old_head| {
+            match old_head.borrow_mut().next.take() {
+                Some(new_head) => {
+                    // Unlink new head->prev from the old head
+                    new_head.borrow_mut().prev = None;
+                    self.head = Some(new_head);
+                }
+                None => {
+                    // Removing the only node, drop the tail reference as well
+                    self.tail = None;
+                }
+            }
+            self.size -= 1;
+            Self::into_value(old_head)
+        })
+    }
+
+    /// Insert a value at index `index`, shifting all values after it one step back
+    /// Returns a handle to the new node, or hands the value back if `index` is past the end of the list
+    pub fn insert(&mut self, index: usize, value: T) -> Result<NodeHandle<T>, T> {
+        if index > self.size {
+            return Err(value);
+        }
+        if index == 0 {
+            return Ok(self.push_front(value));
+        }
+        if index == self.size {
+            return Ok(self.push_back(value));
+        }
+
+        // Unwrapping here since the index is neither the first nor past the last node
+        let next = self.get_link_at(index).unwrap();
+        let node = self.link_before(&next, value);
+        Ok(NodeHandle::from_link(&node))
+    }
+
+    /// Inserts `value` right after the node behind `handle` in constant time, returns a handle to the new node
+    /// Hands the value back along with the error if the handle can't be used with this list
+    pub fn insert_after(
+        &mut self,
+        handle: &NodeHandle<T>,
+        value: T,
+    ) -> Result<NodeHandle<T>, (HandleError, T)> {
+        match self.handle_link(handle) {
+            Ok(node) => Ok(NodeHandle::from_link(&self.link_after(&node, value))),
+            Err(error) => Err((error, value)),
+        }
+    }
+
+    /// Inserts `value` right before the node behind `handle` in constant time, returns a handle to the new node
+    /// Hands the value back along with the error if the handle can't be used with this list
+    pub fn insert_before(
+        &mut self,
+        handle: &NodeHandle<T>,
+        value: T,
+    ) -> Result<NodeHandle<T>, (HandleError, T)> {
+        match self.handle_link(handle) {
+            Ok(node) => Ok(NodeHandle::from_link(&self.link_before(&node, value))),
+            Err(error) => Err((error, value)),
+        }
+    }
+
+    /// Moves the node behind `handle` to the front of the list in constant time
+    pub fn move_to_front(&mut self, handle: &NodeHandle<T>) -> Result<(), HandleError> {
+        let node = self.handle_link(handle)?;
+        self.unlink(&node);
+        self.push_front_link(node);
+        Ok(())
+    }
+
+    /// Moves the node behind `handle` to the back of the list in constant time
+    pub fn move_to_back(&mut self, handle: &NodeHandle<T>) -> Result<(), HandleError> {
+        let node = self.handle_link(handle)?;
+        self.unlink(&node);
+        self.push_back_link(node);
+        Ok(())
+    }
+
+    /// Removes the node behind `handle` in constant time and returns its value
+    pub fn remove_handle(&mut self, handle: &NodeHandle<T>) -> Result<T, HandleError> {
+        let node = self.handle_link(handle)?;
+        self.unlink(&node);
+        Ok(Self::into_value(node))
+    }
+
+    /// Remove the value at index `index`, shifting all values after it one step forward
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        let node = self.get_link_at(index)?;
+        self.unlink(&node);
+        Some(Self::into_value(node))
     }
 
     /// Gets the data at index `index` by cloning
     /// Keep in mind that this is an O(n) operation
-    pub fn get(&self, index: usize) -> Option<i32> {
+    pub fn get(&self, index: usize) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.get_link_at(index)
+            .map(|node| node.as_ref().borrow().value.clone())
+    }
+
+    /// Gets the first value in the list by cloning
+    pub fn front(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.head
+            .as_ref()
+            .map(|node| node.as_ref().borrow().value.clone())
+    }
+
+    /// Gets the last value in the list by cloning
+    pub fn back(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.tail
+            .as_ref()
+            .map(|node| node.as_ref().borrow().value.clone())
+    }
+
+    /// Returns true if any value in the list equals `value`
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.links().any(|node| node.borrow().value == *value)
+    }
+
+    /// Returns the index of the first value matching `predicate`
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<usize> {
+        self.links()
+            .position(|node| predicate(&node.borrow().value))
+    }
+
+    /// Returns the index of the first value equal to `value`
+    pub fn index_of(&self, value: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.position(|v| v == value)
+    }
+
+    /// Returns the index of the last value matching `predicate`, searching from the back
+    pub fn rposition(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<usize> {
+        self.links_rev()
+            .position(|node| predicate(&node.borrow().value))
+            .map(|from_back| self.size - from_back - 1)
+    }
+
+    /// Replaces the value at index `index`, returning the old value
+    /// Returns None and drops `value` if the index is out of bounds
+    pub fn set(&mut self, index: usize, value: T) -> Option<T> {
+        self.get_link_at(index)
+            .map(|node| std::mem::replace(&mut node.borrow_mut().value, value))
+    }
+
+    /// Applies `f` to the value at index `index` in place
+    /// Returns false without calling `f` if the index is out of bounds
+    pub fn update(&mut self, index: usize, f: impl FnOnce(&mut T)) -> bool {
         match self.get_link_at(index) {
-            Some(node) => Some(node.as_ref().borrow().value.clone()),
-            None => None,
+            Some(node) => {
+                f(&mut node.borrow_mut().value);
+                true
+            }
+            None => false,
         }
     }
 
-    // Searches from the beginnnig or end of the list depending on which is closest
-    /// Get the N:th node in the list, only used internally
-    fn get_link_at(&self, index: usize) -> Option<Link> {
-        if index >= self.len() {
-            return None;
+    /// Gets a guard for reading the value at index `index` in place, without cloning it
+    pub fn get_ref(&self, index: usize) -> Option<ElementRef<'_, T>> {
+        self.get_link_at(index).map(|node| ElementRef {
+            node,
+            _list: ListBorrow(PhantomData),
+        })
+    }
+
+    /// Gets a guard for mutating the value at index `index` in place, without cloning it
+    pub fn get_mut(&self, index: usize) -> Option<ElementMut<'_, T>> {
+        self.get_link_at(index).map(|node| ElementMut {
+            node,
+            _list: ListBorrow(PhantomData),
+        })
+    }
+
+    // Index can't be implemented, a &T can't outlive the RefCell borrow it comes from
+    /// Gets a guard for reading the value at index `index`, panics if it is out of bounds
+    pub fn at(&self, index: usize) -> ElementRef<'_, T> {
+        match self.get_ref(index) {
+            Some(element) => element,
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                self.size, index
+            ),
         }
+    }
 
-        let direction_from_head = index <= self.size / 2;
+    /// Gets a guard for mutating the value at index `index`, panics if it is out of bounds
+    pub fn at_mut(&self, index: usize) -> ElementMut<'_, T> {
+        match self.get_mut(index) {
+            Some(element) => element,
+            None => panic!(
+                "index out of bounds: the len is {} but the index is {}",
+                self.size, index
+            ),
+        }
+    }
 
-        let index = if direction_from_head {
-            index
-        } else {
-            self.size - index - 1
+    /// Swaps the values at index `i` and `j`
+    /// Returns false and leaves the list untouched if either index is out of bounds
+    pub fn swap(&mut self, i: usize, j: usize) -> bool {
+        let (low, high) = if i <= j { (i, j) } else { (j, i) };
+        if high >= self.size {
+            return false;
+        }
+        if low == high {
+            return true;
+        }
+
+        // Find the first node, then keep walking from there to the second one
+        let first = self.get_link_at(low).unwrap();
+        let mut second = Rc::clone(&first);
+        for _ in low..high {
+            let next = Rc::clone(second.borrow().next.as_ref().unwrap());
+            second = next;
+        }
+
+        std::mem::swap(
+            &mut first.borrow_mut().value,
+            &mut second.borrow_mut().value,
+        );
+        true
+    }
+
+    /// Removes the first value equal to `value`, returns true if one was found
+    pub fn remove_item(&mut self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        match self.links().find(|node| node.borrow().value == *value) {
+            Some(node) => {
+                self.unlink(&node);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Removes every value equal to `value`, returns how many were removed
+    pub fn remove_all(&mut self, value: &T) -> usize
+    where
+        T: PartialEq,
+    {
+        let size = self.size;
+        self.retain(|v| v != value);
+        size - self.size
+    }
+
+    /// Keeps only the values matching `predicate`, visiting them in order
+    pub fn retain(&mut self, mut predicate: impl FnMut(&T) -> bool) {
+        let mut current = self.head.clone();
+        while let Some(node) = current {
+            // Step ahead first, unlinking clears the node's pointers
+            current = node.borrow().next.clone();
+            if !predicate(&node.borrow().value) {
+                self.unlink(&node);
+            }
+        }
+    }
+
+    /// Returns an iterator that removes and yields the values matching `predicate`
+    /// Values are only visited as the iterator is advanced, anything not visited stays in the list
+    pub fn extract_if<F>(&mut self, predicate: F) -> ExtractIf<'_, T, F>
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        ExtractIf {
+            current: self.head.clone(),
+            unvisited: self.size,
+            list: self,
+            predicate,
+        }
+    }
+
+    /// Returns an iterator that removes and yields every value from the front
+    /// The list is empty once the iterator is dropped, even if it wasn't fully consumed
+    pub fn drain(&mut self) -> Drain<'_, T> {
+        Drain { list: self }
+    }
+
+    /// Removes all values and returns an iterator yielding them from the back to the front
+    /// Each node is freed as soon as its value has been yielded
+    pub fn drain_back(&mut self) -> std::iter::Rev<Drain<'_, T>> {
+        self.drain().rev()
+    }
+
+    /// Removes the values in `range` and returns an iterator yielding them in order
+    /// Bounds past the end of the list are clamped to its length
+    /// Cutting the range out visits the nodes of the range or of the rest, whichever is smaller
+    pub fn drain_range(&mut self, range: Range<usize>) -> DrainRange<T> {
+        let end = range.end.min(self.size);
+        let start = range.start.min(end);
+        DrainRange {
+            removed: self.detach_range(start, end),
+        }
+    }
+
+    /// Shortens the list to its first `len` values, does nothing if it is already shorter
+    /// Takes time linear in the number of values removed, since each of them is dropped
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.size {
+            // The cut off suffix is unlinked node by node when dropped
+            drop(self.detach_range(len, self.size));
+        }
+    }
+
+    /// Resizes the list to `new_len`, either truncating it or pushing clones of `value` to the back
+    pub fn resize(&mut self, new_len: usize, value: T)
+    where
+        T: Clone,
+    {
+        if new_len <= self.size {
+            self.truncate(new_len);
+            return;
+        }
+        while self.size + 1 < new_len {
+            self.push_back(value.clone());
+        }
+        // The last slot gets the value itself, saving a clone
+        self.push_back(value);
+    }
+
+    /// Splits the list in two at index `at`, returning everything from `at` onwards
+    /// Returns an empty list if `at` is past the end of the list
+    /// Not constant time, the nodes of the smaller half are visited to move them to their new list
+    pub fn split_off(&mut self, at: usize) -> List<T> {
+        let at = at.min(self.size);
+        self.detach_range(at, self.size)
+    }
+
+    /// Moves all values of `other` to the back of the list in constant time, leaving `other` empty
+    pub fn append(&mut self, other: &mut List<T>) {
+        let (other_head, other_tail) = match (other.head.take(), other.tail.take()) {
+            (Some(head), Some(tail)) => (head, tail),
+            _ => return,
         };
 
-        // Unwrapping here since we know that the list is not empty
-        let mut current: Link = match direction_from_head {
-            true => Rc::clone(self.head.as_ref().unwrap()),
-            false => Rc::clone(self.tail.as_ref().unwrap()),
+        match self.tail {
+            Some(ref tail) => {
+                // Link tail->next and other head->prev to each other
+                tail.borrow_mut().next = Some(Rc::clone(&other_head));
+                other_head.borrow_mut().prev = Some(Rc::clone(tail));
+            }
+            None => self.head = Some(other_head),
+        }
+        self.tail = Some(other_tail);
+        self.size += other.size;
+        other.size = 0;
+        other.forward_owner(&self.owner);
+    }
+
+    /// Moves all values of `other` to the front of the list in constant time, leaving `other` empty
+    pub fn prepend(&mut self, other: &mut List<T>) {
+        let (other_head, other_tail) = match (other.head.take(), other.tail.take()) {
+            (Some(head), Some(tail)) => (head, tail),
+            _ => return,
         };
 
-        for _ in 0..index {
-            current = match direction_from_head {
-                true => Rc::clone(current.as_ref().borrow().next.as_ref().unwrap()),
-                false => Rc::clone(current.as_ref().borrow().prev.as_ref().unwrap()),
+        match self.head {
+            Some(ref head) => {
+                // Link other tail->next and head->prev to each other
+                other_tail.borrow_mut().next = Some(Rc::clone(head));
+                head.borrow_mut().prev = Some(Rc::clone(&other_tail));
+            }
+            None => self.tail = Some(other_tail),
+        }
+        self.head = Some(other_head);
+        self.size += other.size;
+        other.size = 0;
+        other.forward_owner(&self.owner);
+    }
+
+    /// Replaces the values in `range` with the values from `replace_with`
+    /// Returns an iterator over the removed values, bounds are clamped like in `drain_range`
+    pub fn splice(
+        &mut self,
+        range: Range<usize>,
+        replace_with: impl IntoIterator<Item = T>,
+    ) -> DrainRange<T> {
+        let end = range.end.min(self.size);
+        let start = range.start.min(end);
+
+        // Cut off everything after the range, so the new values can simply be pushed to the back
+        let mut suffix = self.detach_range(end, self.size);
+        let removed = self.detach_range(start, end);
+        replace_with.into_iter().for_each(|value| {
+            self.push_back(value);
+        });
+        self.append(&mut suffix);
+
+        DrainRange { removed }
+    }
+
+    /// Rotates the list `n` steps to the left, so that the value at index `n` becomes the first
+    /// `n` wraps around if it is larger than the length of the list
+    pub fn rotate_left(&mut self, n: usize) {
+        if self.size == 0 {
+            return;
+        }
+        let n = n % self.size;
+        if n == 0 {
+            return;
+        }
+
+        // Unwrapping here since the list is not empty
+        let new_head = self.get_link_at(n).unwrap();
+        let new_tail = new_head.borrow_mut().prev.take().unwrap();
+        new_tail.borrow_mut().next = None;
+
+        // Close the gap between the old tail and the old head
+        let old_head = self.head.take().unwrap();
+        let old_tail = self.tail.take().unwrap();
+        old_head.borrow_mut().prev = Some(Rc::clone(&old_tail));
+        old_tail.borrow_mut().next = Some(old_head);
+
+        self.head = Some(new_head);
+        self.tail = Some(new_tail);
+    }
+
+    /// Rotates the list `n` steps to the right, so that the last `n` values become the first
+    /// `n` wraps around if it is larger than the length of the list
+    pub fn rotate_right(&mut self, n: usize) {
+        if self.size == 0 {
+            return;
+        }
+        self.rotate_left(self.size - n % self.size);
+    }
+
+    /// Reverses the order of the list in place, without moving any values
+    pub fn reverse(&mut self) {
+        let mut current = self.head.clone();
+        while let Some(node) = current {
+            let mut node = node.borrow_mut();
+            let node = &mut *node;
+            std::mem::swap(&mut node.prev, &mut node.next);
+            // The old next pointer is now stored in prev
+            current = node.prev.clone();
+        }
+        std::mem::swap(&mut self.head, &mut self.tail);
+    }
+
+    /// Removes consecutive repeated values, keeping the first of each run
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    /// Removes consecutive values for which `same_bucket` returns true, keeping the first of each run
+    /// Like `Vec::dedup_by`, the value to check is passed first and the value kept before it second
+    pub fn dedup_by(&mut self, mut same_bucket: impl FnMut(&mut T, &mut T) -> bool) {
+        let mut kept = match self.head {
+            Some(ref head) => Rc::clone(head),
+            None => return,
+        };
+        let mut current = kept.borrow().next.clone();
+        while let Some(node) = current {
+            // Step ahead first, unlinking clears the node's pointers
+            current = node.borrow().next.clone();
+            let duplicate = same_bucket(&mut node.borrow_mut().value, &mut kept.borrow_mut().value);
+            if duplicate {
+                self.unlink(&node);
+            } else {
+                kept = node;
+            }
+        }
+    }
+
+    /// Inserts a value after every value less than or equal to it, keeping a sorted list sorted
+    /// Searches from both ends at once, so values ending up near either end are found quickly
+    pub fn insert_sorted(&mut self, value: T)
+    where
+        T: Ord,
+    {
+        let mut front = self.head.clone();
+        let mut back = self.tail.clone();
+
+        // Both searches stop at the same position, whichever gets there first wins
+        loop {
+            front = match front {
+                Some(node) if node.borrow().value > value => {
+                    self.link_before(&node, value);
+                    return;
+                }
+                Some(node) => node.borrow().next.clone(),
+                None => break,
+            };
+            back = match back {
+                Some(node) if node.borrow().value <= value => {
+                    self.link_after(&node, value);
+                    return;
+                }
+                Some(node) => node.borrow().prev.clone(),
+                None => {
+                    self.push_front(value);
+                    return;
+                }
             };
         }
-        Some(current)
+        self.push_back(value);
     }
 
-    pub fn len(&self) -> usize {
-        self.size
+    /// Searches a sorted list for `value` from the front
+    /// Returns the index of the first equal value, or the index it could be inserted at
+    pub fn search_sorted(&self, value: &T) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        self.search_sorted_from(value, &mut self.search_hint())
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Creates a hint for `search_sorted_from`, positioned at the front of the list
+    pub fn search_hint(&self) -> SearchHint<'_, T> {
+        SearchHint {
+            index: 0,
+            node: self.head.clone(),
+            list: self,
+        }
+    }
 
-    const UPPER_BOUNDS: usize = 1000;
+    /// Like `search_sorted`, but walks from the position of the previous search instead of the front
+    /// The hint is moved to the result of the search, a hint from another list starts over from the front
+    pub fn search_sorted_from<'a>(
+        &'a self,
+        value: &T,
+        hint: &mut SearchHint<'a, T>,
+    ) -> Result<usize, usize>
+    where
+        T: Ord,
+    {
+        if !std::ptr::eq(hint.list, self) {
+            *hint = self.search_hint();
+        }
+        let mut index = hint.index;
+        let mut node = hint.node.clone();
 
-    #[test]
-    fn test_push_back() {
-        let mut list = List::new();
-        (0..UPPER_BOUNDS).for_each(|i| list.push_back(i as i32));
+        // Walk back while the value before the current position is still not smaller,
+        // a hint past the end of the list continues from the tail
+        loop {
+            let prev = match node {
+                Some(ref node) => node.borrow().prev.clone(),
+                None => self.tail.clone(),
+            };
+            match prev {
+                Some(prev) if prev.borrow().value >= *value => {
+                    node = Some(prev);
+                    index -= 1;
+                }
+                _ => break,
+            }
+        }
+
+        // Walk forward past every smaller value
+        while let Some(current) = node.clone() {
+            if current.borrow().value >= *value {
+                break;
+            }
+            node = current.borrow().next.clone();
+            index += 1;
+        }
+
+        let found = matches!(node, Some(ref node) if node.borrow().value == *value);
+        hint.index = index;
+        hint.node = node;
+        if found {
+            Ok(index)
+        } else {
+            Err(index)
+        }
     }
 
-    #[test]
-    fn test_len() {
-        let mut list = List::new();
-        (0..UPPER_BOUNDS).for_each(|i| list.push_back(i as i32));
-        assert_eq!(list.len(), UPPER_BOUNDS);
+    /// Splits the list into the values matching `predicate` and the rest, keeping their order
+    /// The nodes themselves are moved over, no values are cloned
+    pub fn partition(mut self, mut predicate: impl FnMut(&T) -> bool) -> (List<T>, List<T>) {
+        let mut matching = List::new();
+        let mut rest = List::new();
+        while let Some(node) = self.pop_front_link() {
+            if predicate(&node.borrow().value) {
+                matching.push_back_link(node);
+            } else {
+                rest.push_back_link(node);
+            }
+        }
+        (matching, rest)
     }
 
-    #[test]
-    fn test_get() {
-        let mut list = List::new();
-        (0..UPPER_BOUNDS).for_each(|i| list.push_back(i as i32));
-        (0..UPPER_BOUNDS).for_each(|i| assert_eq!(list.get(i), Some(i as i32)));
+    /// Groups runs of adjacent values sharing the same key into lists of their own, by cloning
+    pub fn chunk_by<K: PartialEq>(&self, mut key: impl FnMut(&T) -> K) -> List<List<T>>
+    where
+        T: Clone,
+    {
+        let mut chunks: List<List<T>> = List::new();
+        let mut last_key = None;
+        for node in self.links() {
+            let node = node.borrow();
+            let node_key = key(&node.value);
+            if last_key.as_ref() != Some(&node_key) {
+                chunks.push_back(List::new());
+                last_key = Some(node_key);
+            }
+            // Unwrapping here since a chunk was pushed for the first value
+            let last_chunk = chunks.tail.as_ref().unwrap();
+            last_chunk.borrow_mut().value.push_back(node.value.clone());
+        }
+        chunks
     }
 
-    #[test]
-    fn test_empty_len() {
-        assert_eq!(List::new().len(), 0);
+    /// Merges two sorted lists into one sorted list by relinking their nodes
+    /// Equal values from this list are placed before the ones from `other`
+    pub fn merge(mut self, mut other: List<T>) -> List<T>
+    where
+        T: Ord,
+    {
+        let mut merged = List::new();
+        while let (Some(ours), Some(theirs)) = (&self.head, &other.head) {
+            let take_other = theirs.borrow().value < ours.borrow().value;
+            // Unwrapping here since both lists were just checked to be non-empty
+            let node = match take_other {
+                true => other.pop_front_link().unwrap(),
+                false => self.pop_front_link().unwrap(),
+            };
+            merged.push_back_link(node);
+        }
+
+        // At most one of the lists has values left, and they are all larger
+        merged.append(&mut self);
+        merged.append(&mut other);
+        merged
     }
 
-    #[test]
-    fn test_large_data() {
-        let mut list = List::new();
-        (0..UPPER_BOUNDS * 10).for_each(|i| list.push_back(i as i32));
-        assert_eq!(list.len(), UPPER_BOUNDS * 10);
+    /// Links any number of lists together end to end, in order
+    /// Only the lists are iterated over, their values are never visited
+    pub fn concat(lists: impl IntoIterator<Item = List<T>>) -> List<T> {
+        let mut combined = List::new();
+        for mut list in lists {
+            combined.append(&mut list);
+        }
+        combined
     }
 
-    #[test]
-    fn test_getting_from_large_data() {
-        let mut list: List = List::new();
+    /// Pairs up the values of both lists by position, stopping at the end of the shorter one
+    pub fn zip<U>(mut self, mut other: List<U>) -> List<(T, U)> {
+        let mut zipped = List::new();
+        while let (Some(ours), Some(theirs)) = (self.pop_front(), other.pop_front()) {
+            zipped.push_back((ours, theirs));
+        }
+        zipped
+    }
 
-        (0..10000).for_each(|i| list.push_back(i as i32));
+    /// Pushes every value from `values` onto the back of the list
+    pub fn push_back_iter(&mut self, values: impl IntoIterator<Item = T>) {
+        // Build the new chain on its own first, so the tail is only relinked once
+        let mut chain: List<T> = values.into_iter().collect();
+        self.append(&mut chain);
+    }
 
-        // Not doing exhaustive testing here since get is O(n)
-        [0, 100, 2314, 3948, 231, 9489, 9999].iter().for_each(|i| {
-            assert_eq!(list.get(*i as usize), Some(*i as i32));
-        });
+    /// Pushes clones of every value in `values` onto the back of the list
+    pub fn extend_from_slice(&mut self, values: &[T])
+    where
+        T: Clone,
+    {
+        self.push_back_iter(values.iter().cloned());
     }
 
-    #[test]
-    fn test_out_of_bounds() {
-        let mut list = List::new();
-        (0..20).for_each(|i| list.push_back(i as i32));
-        assert_eq!(list.get(20), None);
-        assert_eq!(list.get(20 * 20), None);
+    /// Moves all values into a new list in constant time, leaving this one empty
+    pub fn take(&mut self) -> List<T> {
+        std::mem::take(self)
+    }
+
+    /// Exchanges the contents of two lists in constant time
+    pub fn swap_with(&mut self, other: &mut List<T>) {
+        std::mem::swap(self, other);
+    }
+
+    /// Gets clones of the values at every index in `indices`, walking the list only once
+    /// The result is in the same order as `indices`, with None for indices out of bounds
+    pub fn get_many(&self, indices: &[usize]) -> Vec<Option<T>>
+    where
+        T: Clone,
+    {
+        // Visit the requested indices in ascending order, remembering where each one goes
+        let mut order: Vec<usize> = (0..indices.len()).collect();
+        order.sort_unstable_by_key(|&i| indices[i]);
+
+        let mut values = vec![None; indices.len()];
+        let mut links = self.links().enumerate();
+        let mut current = links.next();
+        for i in order {
+            while let Some((index, _)) = current {
+                if index >= indices[i] {
+                    break;
+                }
+                current = links.next();
+            }
+            match current {
+                Some((index, ref node)) if index == indices[i] => {
+                    values[i] = Some(node.borrow().value.clone());
+                }
+                // Every index left is out of bounds
+                _ => break,
+            }
+        }
+        values
+    }
+
+    /// Gets a clone of the first value matching `predicate`
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.links()
+            .find(|node| predicate(&node.borrow().value))
+            .map(|node| node.borrow().value.clone())
+    }
+
+    /// Gets a clone of the last value matching `predicate`, searching from the back
+    pub fn find_from_back(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.links_rev()
+            .find(|node| predicate(&node.borrow().value))
+            .map(|node| node.borrow().value.clone())
+    }
+
+    /// Applies `f` to the values in order and returns the first result that isn't None
+    pub fn find_map<U>(&self, mut f: impl FnMut(&T) -> Option<U>) -> Option<U> {
+        self.links().find_map(|node| f(&node.borrow().value))
+    }
+
+    /// Counts the values matching `predicate`
+    pub fn count_where(&self, mut predicate: impl FnMut(&T) -> bool) -> usize {
+        self.links()
+            .filter(|node| predicate(&node.borrow().value))
+            .count()
+    }
+
+    /// Returns true if the list begins with the values in `prefix`
+    pub fn starts_with(&self, prefix: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        prefix.len() <= self.size
+            && self
+                .links()
+                .zip(prefix)
+                .all(|(node, value)| node.borrow().value == *value)
+    }
+
+    /// Returns true if the list ends with the values in `suffix`, comparing from the back
+    pub fn ends_with(&self, suffix: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        suffix.len() <= self.size
+            && self
+                .links_rev()
+                .zip(suffix.iter().rev())
+                .all(|(node, value)| node.borrow().value == *value)
+    }
+
+    /// Removes `prefix` from the front of the list if it starts with it, returns true if it did
+    pub fn strip_prefix(&mut self, prefix: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        if !self.starts_with(prefix) {
+            return false;
+        }
+        drop(self.detach_range(0, prefix.len()));
+        true
+    }
+
+    /// Removes `suffix` from the back of the list if it ends with it, returns true if it did
+    pub fn strip_suffix(&mut self, suffix: &[T]) -> bool
+    where
+        T: PartialEq,
+    {
+        if !self.ends_with(suffix) {
+            return false;
+        }
+        drop(self.detach_range(self.size - suffix.len(), self.size));
+        true
+    }
+
+    /// Creates a new list holding the values of this one repeated `n` times, by cloning
+    pub fn repeat(&self, n: usize) -> List<T>
+    where
+        T: Clone,
+    {
+        let mut repeated = List::new();
+        for _ in 0..n {
+            self.links().for_each(|node| {
+                repeated.push_back(node.borrow().value.clone());
+            });
+        }
+        repeated
+    }
+
+    /// Returns an iterator yielding clones of the values from front to back
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head.clone(),
+            next_back: self.tail.clone(),
+            len: self.size,
+            _list: ListBorrow(PhantomData),
+        }
+    }
+
+    /// Applies `f` to every value in place, from front to back
+    pub fn for_each_mut(&mut self, mut f: impl FnMut(&mut T)) {
+        self.links()
+            .for_each(|node| f(&mut node.borrow_mut().value));
+    }
+
+    /// Returns an iterator yielding guards that dereference to the values, for mutating them in place
+    /// While a guard is alive its node holds `T::default()`, the value is put back when it's dropped
+    /// That placeholder is why `T: Default` is required. A guard leaked with `mem::forget` never puts
+    /// its value back, the node keeps the placeholder and handles to it report `HandleError::Borrowed` until it is removed
+    pub fn iter_mut(&mut self) -> IterMut<'_, T>
+    where
+        T: Default,
+    {
+        IterMut {
+            next: self.head.clone(),
+            next_back: self.tail.clone(),
+            len: self.size,
+            _list: ListBorrow(PhantomData),
+        }
+    }
+
+    /// Returns an iterator over all overlapping runs of `size` values, as vectors of clones
+    /// Yields nothing if `size` is zero or larger than the list
+    pub fn windows(&self, size: usize) -> Windows<'_, T> {
+        let remaining = match size {
+            0 => 0,
+            _ => (self.size + 1).saturating_sub(size),
+        };
+        Windows {
+            start: self.head.clone(),
+            size,
+            remaining,
+            _list: ListBorrow(PhantomData),
+        }
+    }
+
+    /// Returns an iterator over consecutive runs of `size` values, as vectors of clones
+    /// The last chunk is shorter if the length isn't a multiple of `size`, nothing is yielded if `size` is zero
+    pub fn chunks(&self, size: usize) -> Chunks<'_, T> {
+        Chunks {
+            next: self.head.clone(),
+            size,
+            remaining: if size == 0 { 0 } else { self.size },
+            _list: ListBorrow(PhantomData),
+        }
+    }
+
+    /// Returns an iterator over clones of every pair of adjacent values
+    pub fn pairs(&self) -> Pairs<'_, T> {
+        Pairs {
+            first: self.head.clone(),
+            remaining: self.size.saturating_sub(1),
+            _list: ListBorrow(PhantomData),
+        }
+    }
+
+    /// Returns an iterator yielding clones of the values over and over, wrapping from back to front
+    /// Yields nothing at all if the list is empty
+    pub fn cycle_iter(&self) -> CycleIter<'_, T> {
+        CycleIter {
+            next: self.head.clone(),
+            head: self.head.clone(),
+            _list: ListBorrow(PhantomData),
+        }
+    }
+
+    /// Returns an iterator yielding clones of the values in `range`
+    /// Both ends of the range are found from the nearest end of the list, bounds are clamped like in `drain_range`
+    pub fn iter_range(&self, range: Range<usize>) -> Iter<'_, T> {
+        let end = range.end.min(self.size);
+        let start = range.start.min(end);
+        if start == end {
+            return Iter {
+                next: None,
+                next_back: None,
+                len: 0,
+                _list: ListBorrow(PhantomData),
+            };
+        }
+        Iter {
+            next: self.get_link_at(start),
+            next_back: self.get_link_at(end - 1),
+            len: end - start,
+            _list: ListBorrow(PhantomData),
+        }
+    }
+
+    /// Creates a new list holding the result of applying `f` to every value
+    pub fn map<U>(&self, mut f: impl FnMut(&T) -> U) -> List<U> {
+        let mut mapped = List::new();
+        self.links().for_each(|node| {
+            mapped.push_back(f(&node.borrow().value));
+        });
+        mapped
+    }
+
+    /// Creates a new list holding clones of the values matching `predicate`
+    pub fn filter(&self, mut predicate: impl FnMut(&T) -> bool) -> List<T>
+    where
+        T: Clone,
+    {
+        let mut filtered = List::new();
+        for node in self.links() {
+            let node = node.borrow();
+            if predicate(&node.value) {
+                filtered.push_back(node.value.clone());
+            }
+        }
+        filtered
+    }
+
+    /// Replaces every value with the result of passing it to `f`, reusing the existing nodes
+    /// The value is moved out of its node for the call, leaving `T::default()` behind until it returns
+    /// That placeholder is why `T: Default` is required. If `f` panics the value it was passed is lost,
+    /// its node keeps the placeholder while the values after it stay untouched
+    pub fn map_in_place(&mut self, mut f: impl FnMut(T) -> T)
+    where
+        T: Default,
+    {
+        self.links().for_each(|node| {
+            // Stays borrowed during the call, so handles can't read the placeholder
+            let mut node = node.borrow_mut();
+            let value = std::mem::take(&mut node.value);
+            node.value = f(value);
+        });
+    }
+
+    /// Consumes the list into a stream yielding the values from front to back
+    #[cfg(feature = "futures")]
+    pub fn into_stream(self) -> IntoStream<T> {
+        IntoStream { list: self }
+    }
+
+    /// Returns an iterator yielding every index along with a handle to the node at that index
+    pub fn iter_handles(&self) -> Handles<'_, T> {
+        Handles {
+            next: self.head.clone(),
+            next_back: self.tail.clone(),
+            index: 0,
+            len: self.size,
+            _list: ListBorrow(PhantomData),
+        }
+    }
+
+    /// Returns a value that displays every element using `format`, with `sep` between them
+    /// Works with `write!` on both `fmt::Write` and `io::Write`, without building a String first
+    pub fn format_with<'a, F>(&'a self, sep: &'a str, format: F) -> FormatWith<'a, T, F>
+    where
+        F: Fn(&T, &mut fmt::Formatter<'_>) -> fmt::Result,
+    {
+        FormatWith {
+            list: self,
+            sep,
+            format,
+        }
+    }
+
+    /// Copies the values into a new Vec, in order
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.iter().collect()
+    }
+
+    /// Returns a cursor pointing at the first value, or at the ghost position if the list is empty
+    pub fn cursor_front(&self) -> Cursor<'_, T> {
+        Cursor {
+            position: CursorPosition {
+                current: self.head.clone(),
+                index: 0,
+            },
+            list: self,
+        }
+    }
+
+    /// Returns a cursor pointing at the last value, or at the ghost position if the list is empty
+    pub fn cursor_back(&self) -> Cursor<'_, T> {
+        Cursor {
+            position: CursorPosition {
+                current: self.tail.clone(),
+                index: self.size.saturating_sub(1),
+            },
+            list: self,
+        }
+    }
+
+    /// Returns a cursor pointing at the node behind `handle`
+    pub fn cursor_at(&self, handle: &NodeHandle<T>) -> Result<Cursor<'_, T>, HandleError> {
+        let node = self.handle_link(handle)?;
+        Ok(Cursor {
+            position: CursorPosition {
+                index: self.index_of_link(&node)?,
+                current: Some(node),
+            },
+            list: self,
+        })
+    }
+
+    /// Returns a cursor that can edit the list, pointing at the node behind `handle`
+    /// Together with `CursorMut::handle` this lets any number of positions be edited in turn
+    pub fn cursor_at_mut(
+        &mut self,
+        handle: &NodeHandle<T>,
+    ) -> Result<CursorMut<'_, T>, HandleError> {
+        let node = self.handle_link(handle)?;
+        Ok(CursorMut {
+            position: CursorPosition {
+                index: self.index_of_link(&node)?,
+                current: Some(node),
+            },
+            list: self,
+        })
+    }
+
+    /// Returns a cursor that can edit the list, pointing at the first value
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            position: CursorPosition {
+                current: self.head.clone(),
+                index: 0,
+            },
+            list: self,
+        }
+    }
+
+    /// Returns a cursor that can edit the list, pointing at the last value
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            position: CursorPosition {
+                current: self.tail.clone(),
+                index: self.size.saturating_sub(1),
+            },
+            list: self,
+        }
+    }
+
+    /// Gets the entry at index `index`, for modifying the value there or inserting one in a single traversal
+    /// The entry at the end of the list is vacant, inserting into it pushes onto the back
+    /// Returns None if `index` is past the end of the list
+    pub fn entry(&mut self, index: usize) -> Option<Entry<'_, T>> {
+        if index == self.size {
+            return Some(Entry::Vacant(VacantEntry { list: self }));
+        }
+        let node = self.get_link_at(index)?;
+        Some(Entry::Occupied(OccupiedEntry {
+            list: self,
+            node: Rc::downgrade(&node),
+        }))
+    }
+
+    // Searches from the beginnnig or end of the list depending on which is closest
+    /// Get the N:th node in the list, only used internally
+    fn get_link_at(&self, index: usize) -> Option<Link<T>> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let direction_from_head = index <= self.size / 2;
+
+        let index = if direction_from_head {
+            index
+        } else {
+            self.size - index - 1
+        };
+
+        // Unwrapping here since we know that the list is not empty
+        let mut current: Link<T> = match direction_from_head {
+            true => Rc::clone(self.head.as_ref().unwrap()),
+            false => Rc::clone(self.tail.as_ref().unwrap()),
+        };
+
+        for _ in 0..index {
+            current = match direction_from_head {
+                true => Rc::clone(current.as_ref().borrow().next.as_ref().unwrap()),
+                false => Rc::clone(current.as_ref().borrow().prev.as_ref().unwrap()),
+            };
+        }
+        Some(current)
+    }
+
+    // The node must belong to this list
+    /// Link a new node holding `value` in right before `node`, only used internally
+    fn link_before(&mut self, node: &Link<T>, value: T) -> Link<T> {
+        let prev = node.borrow().prev.clone();
+        let new_node = Rc::new(RefCell::new(Node {
+            value,
+            prev: prev.clone(),
+            next: Some(Rc::clone(node)),
+            owner: Rc::clone(&self.owner),
+            moved_out: false,
+        }));
+
+        // Splice the new node in between prev and node
+        match prev {
+            Some(ref prev) => prev.borrow_mut().next = Some(Rc::clone(&new_node)),
+            None => self.head = Some(Rc::clone(&new_node)),
+        }
+        node.borrow_mut().prev = Some(Rc::clone(&new_node));
+        self.size += 1;
+        new_node
+    }
+
+    // The node must belong to this list
+    /// Link a new node holding `value` in right after `node`, only used internally
+    fn link_after(&mut self, node: &Link<T>, value: T) -> Link<T> {
+        let next = node.borrow().next.clone();
+        let new_node = Rc::new(RefCell::new(Node {
+            value,
+            prev: Some(Rc::clone(node)),
+            next: next.clone(),
+            owner: Rc::clone(&self.owner),
+            moved_out: false,
+        }));
+
+        // Splice the new node in between node and next
+        match next {
+            Some(ref next) => next.borrow_mut().prev = Some(Rc::clone(&new_node)),
+            None => self.tail = Some(Rc::clone(&new_node)),
+        }
+        node.borrow_mut().next = Some(Rc::clone(&new_node));
+        self.size += 1;
+        new_node
+    }
+
+    // The node must belong to this list
+    /// Detach a node from its neighbours, updating head and tail, only used internally
+    fn unlink(&mut self, node: &Link<T>) {
+        self.unlink_chain(node, node, 1);
+    }
+
+    // The chain must belong to this list and hold `len` nodes from `first` to `last`
+    /// Detach a chain of nodes from its neighbours, updating head and tail, only used internally
+    fn unlink_chain(&mut self, first: &Link<T>, last: &Link<T>, len: usize) {
+        let prev = first.borrow_mut().prev.take();
+        let next = last.borrow_mut().next.take();
+
+        // Reconnect the neighbours, or move head/tail if the chain was at an end
+        match prev {
+            Some(ref prev) => prev.borrow_mut().next = next.clone(),
+            None => self.head = next.clone(),
+        }
+        match next {
+            Some(ref next) => next.borrow_mut().prev = prev,
+            None => self.tail = prev,
+        }
+        self.size -= len;
+    }
+
+    /// Detach the first node and hand it out without unwrapping the value, only used internally
+    fn pop_front_link(&mut self) -> Option<Link<T>> {
+        let head = self.head.clone()?;
+        self.unlink(&head);
+        Some(head)
+    }
+
+    // The node must not be linked into any list
+    /// Attach a detached node at the back of the list, only used internally
+    fn push_back_link(&mut self, node: Link<T>) {
+        node.borrow_mut().owner = Rc::clone(&self.owner);
+        match self.tail.take() {
+            Some(tail) => {
+                node.borrow_mut().prev = Some(Rc::clone(&tail));
+                tail.borrow_mut().next = Some(Rc::clone(&node));
+            }
+            None => self.head = Some(Rc::clone(&node)),
+        }
+        self.tail = Some(node);
+        self.size += 1;
+    }
+
+    // The node must not be linked into any list
+    /// Attach a detached node at the front of the list, only used internally
+    fn push_front_link(&mut self, node: Link<T>) {
+        node.borrow_mut().owner = Rc::clone(&self.owner);
+        match self.head.take() {
+            Some(head) => {
+                node.borrow_mut().next = Some(Rc::clone(&head));
+                head.borrow_mut().prev = Some(Rc::clone(&node));
+            }
+            None => self.tail = Some(Rc::clone(&node)),
+        }
+        self.head = Some(node);
+        self.size += 1;
+    }
+
+    // The node must belong to this list
+    /// Link all nodes of `other` in right after `node`, only used internally
+    fn link_list_after(&mut self, node: &Link<T>, mut other: List<T>) {
+        let (first, last) = match (other.head.take(), other.tail.take()) {
+            (Some(head), Some(tail)) => (head, tail),
+            _ => return,
+        };
+
+        // Splice the chain in between node and next
+        let next = node.borrow_mut().next.replace(Rc::clone(&first));
+        first.borrow_mut().prev = Some(Rc::clone(node));
+        match next {
+            Some(ref next) => next.borrow_mut().prev = Some(Rc::clone(&last)),
+            None => self.tail = Some(Rc::clone(&last)),
+        }
+        last.borrow_mut().next = next;
+        self.size += other.size;
+        other.size = 0;
+        other.forward_owner(&self.owner);
+    }
+
+    // Both bounds must be within the list and `start <= end`
+    /// Cut the values in `start..end` out into a list of their own, only used internally
+    fn detach_range(&mut self, start: usize, end: usize) -> List<T> {
+        if start == end {
+            return List::new();
+        }
+
+        // Unwrapping here since the range is within bounds and not empty
+        let first = self.get_link_at(start).unwrap();
+        let last = self.get_link_at(end - 1).unwrap();
+        self.detach_chain(first, last, end - start)
+    }
+
+    // The chain must belong to this list and hold `len` nodes from `first` to `last`
+    /// Cut a chain of nodes out into a list of their own, only used internally
+    fn detach_chain(&mut self, first: Link<T>, last: Link<T>, len: usize) -> List<T> {
+        self.unlink_chain(&first, &last, len);
+
+        let mut detached = List {
+            size: len,
+            head: Some(first),
+            tail: Some(last),
+            owner: Owner::new(),
+        };
+        // Only the smaller side gets retagged, the other one keeps the current owner
+        if detached.size > self.size {
+            std::mem::swap(&mut self.owner, &mut detached.owner);
+            self.retag();
+        } else {
+            detached.retag();
+        }
+        detached
+    }
+
+    /// Point every node at the owner of this list, only used internally
+    fn retag(&self) {
+        for node in self.links() {
+            node.borrow_mut().owner = Rc::clone(&self.owner);
+        }
+    }
+
+    // Every node of this list must have been moved to the list owned by `owner`
+    /// Forward the owner of this list to `owner` and start over with a new one, only used internally
+    fn forward_owner(&mut self, owner: &Rc<Owner>) {
+        *self.owner.forward.borrow_mut() = Some(Rc::clone(owner));
+        self.owner = Owner::new();
+    }
+
+    // The node must belong to this list
+    /// Count the steps from `node` to whichever end is closer, only used internally
+    fn index_of_link(&self, node: &Link<T>) -> Result<usize, HandleError> {
+        let mut backward = Rc::clone(node);
+        let mut forward = Rc::clone(node);
+        let mut steps = 0;
+        loop {
+            let prev = Self::try_borrow_node(&backward)?.prev.clone();
+            let next = Self::try_borrow_node(&forward)?.next.clone();
+            match (prev, next) {
+                (None, _) => return Ok(steps),
+                (_, None) => return Ok(self.size - 1 - steps),
+                (Some(prev), Some(next)) => {
+                    backward = prev;
+                    forward = next;
+                    steps += 1;
+                }
+            }
+        }
+    }
+
+    // Guards can hold a borrow of any node while the list is only borrowed immutably
+    /// Borrow `node`, failing instead of panicking while a guard writes to it, only used internally
+    fn try_borrow_node(node: &Link<T>) -> Result<Ref<'_, Node<T>>, HandleError> {
+        node.try_borrow().map_err(|_| HandleError::Borrowed)
+    }
+
+    /// Checks that `node` is linked into this list, only used internally
+    fn owns(&self, node: &Link<T>) -> Result<(), HandleError> {
+        let owner = Owner::resolve(&Self::try_borrow_node(node)?.owner);
+        if !Rc::ptr_eq(&owner, &self.owner) {
+            return Err(HandleError::ForeignList);
+        }
+        // Skip the forwarding next time, unless a guard is using the node right now
+        if let Ok(mut node) = node.try_borrow_mut() {
+            node.owner = owner;
+        }
+        Ok(())
+    }
+
+    /// Get the node behind `handle` if it is linked into this list, only used internally
+    fn handle_link(&self, handle: &NodeHandle<T>) -> Result<Link<T>, HandleError> {
+        let node = handle.node.upgrade().ok_or(HandleError::Removed)?;
+        self.owns(&node)?;
+        Ok(node)
+    }
+
+    /// Walk the nodes from head to tail, only used internally
+    fn links(&self) -> impl Iterator<Item = Link<T>> {
+        std::iter::successors(self.head.clone(), |node| node.borrow().next.clone())
+    }
+
+    /// Walk the nodes from tail to head, only used internally
+    fn links_rev(&self) -> impl Iterator<Item = Link<T>> {
+        std::iter::successors(self.tail.clone(), |node| node.borrow().prev.clone())
+    }
+
+    // Every other pointer to the node must have been cleared before calling this
+    /// Take the value out of an unlinked node, only used internally
+    fn into_value(link: Link<T>) -> T {
+        match Rc::try_unwrap(link) {
+            Ok(node) => node.into_inner().value,
+            Err(_) => unreachable!("unlinked node is still referenced"),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Returns true if the list holds no values
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Removes all values from the list
+    pub fn clear(&mut self) {
+        self.tail = None;
+        let mut current = self.head.take();
+
+        // Break the links one node at a time, dropping a long chain in one go would recurse
+        while let Some(node) = current {
+            let mut node = node.borrow_mut();
+            node.prev = None;
+            current = node.next.take();
+        }
+        self.size = 0;
+    }
+}
+
+impl<T, U> List<(T, U)> {
+    /// Splits a list of pairs into a list of the first values and a list of the second values
+    pub fn unzip(mut self) -> (List<T>, List<U>) {
+        let mut firsts = List::new();
+        let mut seconds = List::new();
+        while let Some((first, second)) = self.pop_front() {
+            firsts.push_back(first);
+            seconds.push_back(second);
+        }
+        (firsts, seconds)
+    }
+}
+
+impl<T> List<List<T>> {
+    /// Links all inner lists together end to end, without visiting their values
+    pub fn flatten(mut self) -> List<T> {
+        List::concat(self.drain())
+    }
+
+    /// Links all inner lists together end to end with a clone of `separator` between each pair
+    pub fn join(mut self, separator: T) -> List<T>
+    where
+        T: Clone,
+    {
+        let mut joined = List::new();
+        if let Some(mut first) = self.pop_front() {
+            joined.append(&mut first);
+        }
+        while let Some(mut list) = self.pop_front() {
+            joined.push_back(separator.clone());
+            joined.append(&mut list);
+        }
+        joined
+    }
+}
+
+// Nodes point at each other in both directions, so they have to be unlinked manually
+impl<T> Drop for List<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        List::new()
+    }
+}
+
+// Cloning the head Rc would share the nodes, so every value is copied into a fresh node
+impl<T: Clone> Clone for List<T> {
+    fn clone(&self) -> Self {
+        self.iter().collect()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for List<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut entries = f.debug_list();
+        for node in self.links() {
+            entries.entry(&node.borrow().value);
+        }
+        entries.finish()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for List<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}]", self.format_with(", ", T::fmt))
+    }
+}
+
+impl<T: PartialEq> PartialEq for List<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size
+            && self
+                .links()
+                .zip(other.links())
+                .all(|(ours, theirs)| ours.borrow().value == theirs.borrow().value)
+    }
+}
+
+impl<T: Eq> Eq for List<T> {}
+
+impl<T: PartialEq<U>, U> PartialEq<[U]> for List<T> {
+    fn eq(&self, other: &[U]) -> bool {
+        self.size == other.len()
+            && self
+                .links()
+                .zip(other)
+                .all(|(ours, theirs)| ours.borrow().value == *theirs)
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<&[U]> for List<T> {
+    fn eq(&self, other: &&[U]) -> bool {
+        *self == **other
+    }
+}
+
+impl<T: PartialEq<U>, U> PartialEq<Vec<U>> for List<T> {
+    fn eq(&self, other: &Vec<U>) -> bool {
+        *self == **other
+    }
+}
+
+impl<T: PartialEq<U>, U, const N: usize> PartialEq<[U; N]> for List<T> {
+    fn eq(&self, other: &[U; N]) -> bool {
+        *self == other[..]
+    }
+}
+
+impl<T: PartialOrd> PartialOrd for List<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        for (ours, theirs) in self.links().zip(other.links()) {
+            match ours.borrow().value.partial_cmp(&theirs.borrow().value) {
+                Some(std::cmp::Ordering::Equal) => {}
+                ordering => return ordering,
+            }
+        }
+        self.size.partial_cmp(&other.size)
+    }
+}
+
+// get_mut and the guards change values through a shared reference, so a list used as
+// a key in a BTreeMap or BTreeSet must not be changed that way or the collection breaks
+impl<T: Ord> Ord for List<T> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        for (ours, theirs) in self.links().zip(other.links()) {
+            match ours.borrow().value.cmp(&theirs.borrow().value) {
+                std::cmp::Ordering::Equal => {}
+                ordering => return ordering,
+            }
+        }
+        self.size.cmp(&other.size)
+    }
+}
+
+// Same as with Ord, a list used as a key in a HashMap or HashSet must not be changed
+// through get_mut or the other guards, or it ends up in the wrong bucket
+impl<T: std::hash::Hash> std::hash::Hash for List<T> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        // The length keeps lists of lists from colliding when flattened into the hasher
+        self.size.hash(state);
+        for node in self.links() {
+            node.borrow().value.hash(state);
+        }
+    }
+}
+
+impl<T> From<Vec<T>> for List<T> {
+    fn from(values: Vec<T>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<T: Clone> From<&[T]> for List<T> {
+    fn from(values: &[T]) -> Self {
+        values.iter().cloned().collect()
+    }
+}
+
+impl<T> From<List<T>> for Vec<T> {
+    fn from(list: List<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl<T> From<std::collections::LinkedList<T>> for List<T> {
+    fn from(values: std::collections::LinkedList<T>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<T> From<List<T>> for std::collections::LinkedList<T> {
+    fn from(list: List<T>) -> Self {
+        list.into_iter().collect()
+    }
+}
+
+impl<T> From<std::collections::VecDeque<T>> for List<T> {
+    fn from(values: std::collections::VecDeque<T>) -> Self {
+        values.into_iter().collect()
+    }
+}
+
+impl<T> From<List<T>> for std::collections::VecDeque<T> {
+    fn from(list: List<T>) -> Self {
+        let mut values = std::collections::VecDeque::with_capacity(list.len());
+        values.extend(list);
+        values
+    }
+}
+
+// Dropping the Rc of a node never touches the list, so without a Drop impl somewhere the borrow
+// checker would end the borrow early and let the list be changed while a node is still held,
+// which would make unwrapping that node fail once it is removed
+/// Marks a borrow of the list that lasts until the value holding it is dropped, only used internally
+struct ListBorrow<'a, T>(PhantomData<&'a List<T>>);
+
+impl<T> Drop for ListBorrow<'_, T> {
+    fn drop(&mut self) {}
+}
+
+// A Ref can't be stored next to the Rc it borrows from in safe Rust,
+// so the guards keep the node alive and hand out the actual borrow on request
+/// Guard for reading a single value in place, returned by `List::get_ref`
+///
+/// The list stays borrowed until the guard is dropped, so the node can't be removed meanwhile
+/// ```compile_fail
+/// # use rust_safe_linked_list::List;
+/// let mut list = List::new();
+/// list.push_back(1);
+/// let guard = list.get_ref(0).unwrap();
+/// let _ = *guard.borrow();
+/// list.pop_front();
+/// ```
+pub struct ElementRef<'a, T> {
+    node: Link<T>,
+    _list: ListBorrow<'a, T>,
+}
+
+impl<T> ElementRef<'_, T> {
+    /// Borrows the value, panics if it is currently borrowed mutably
+    pub fn borrow(&self) -> Ref<'_, T> {
+        Ref::map(self.node.borrow(), |node| &node.value)
+    }
+}
+
+/// Guard for mutating a single value in place, returned by `List::get_mut`
+pub struct ElementMut<'a, T> {
+    node: Link<T>,
+    _list: ListBorrow<'a, T>,
+}
+
+impl<T> ElementMut<'_, T> {
+    /// Borrows the value, panics if it is currently borrowed mutably
+    pub fn borrow(&self) -> Ref<'_, T> {
+        Ref::map(self.node.borrow(), |node| &node.value)
+    }
+
+    /// Mutably borrows the value, panics if it is currently borrowed
+    pub fn borrow_mut(&self) -> RefMut<'_, T> {
+        RefMut::map(self.node.borrow_mut(), |node| &mut node.value)
+    }
+}
+
+/// Iterator removing the values matching a predicate, returned by `List::extract_if`
+pub struct ExtractIf<'a, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    list: &'a mut List<T>,
+    current: Option<Link<T>>,
+    // Upper bound for how many values can still be extracted
+    unvisited: usize,
+    predicate: F,
+}
+
+impl<T, F> Iterator for ExtractIf<'_, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        while let Some(node) = self.current.take() {
+            // Step ahead first, unlinking clears the node's pointers
+            self.current = node.borrow().next.clone();
+            self.unvisited -= 1;
+            if (self.predicate)(&mut node.borrow_mut().value) {
+                self.list.unlink(&node);
+                return Some(List::into_value(node));
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.unvisited))
+    }
+}
+
+impl<T, F> FusedIterator for ExtractIf<'_, T, F> where F: FnMut(&mut T) -> bool {}
+
+// Holds on to the borrow while a node is held, see ListBorrow
+impl<T, F> Drop for ExtractIf<'_, T, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    fn drop(&mut self) {}
+}
+
+/// Iterator moving every value out of the list, returned by `List::drain`
+pub struct Drain<'a, T> {
+    list: &'a mut List<T>,
+}
+
+impl<T> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len(), Some(self.list.len()))
+    }
+}
+
+impl<T> DoubleEndedIterator for Drain<'_, T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for Drain<'_, T> {}
+
+impl<T> FusedIterator for Drain<'_, T> {}
+
+impl<T> Drop for Drain<'_, T> {
+    fn drop(&mut self) {
+        self.list.clear();
+    }
+}
+
+/// Iterator over the values cut out of the list, returned by `List::drain_range`
+pub struct DrainRange<T> {
+    removed: List<T>,
+}
+
+impl<T> Iterator for DrainRange<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.removed.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.removed.len(), Some(self.removed.len()))
+    }
+}
+
+impl<T> DoubleEndedIterator for DrainRange<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.removed.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for DrainRange<T> {}
+
+impl<T> FusedIterator for DrainRange<T> {}
+
+/// Position of a previous search in a sorted list, returned by `List::search_hint`
+pub struct SearchHint<'a, T> {
+    index: usize,
+    // None when positioned past the end of the list
+    node: Option<Link<T>>,
+    list: &'a List<T>,
+}
+
+impl<T> SearchHint<'_, T> {
+    /// Index the hint is positioned at
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
+// Holds on to the borrow while a node is held, see ListBorrow
+impl<T> Drop for SearchHint<'_, T> {
+    fn drop(&mut self) {}
+}
+
+/// Iterator over clones of the values in the list, returned by `List::iter`
+///
+/// The list stays borrowed until the iterator is dropped, even when it is only partly used
+/// ```compile_fail
+/// # use rust_safe_linked_list::List;
+/// let mut list = List::new();
+/// list.push_back(1);
+/// list.push_back(2);
+/// let mut iter = list.iter();
+/// iter.next();
+/// list.clear();
+/// ```
+pub struct Iter<'a, T> {
+    next: Option<Link<T>>,
+    next_back: Option<Link<T>>,
+    // Values left between next and next_back, so both ends stop once they meet
+    len: usize,
+    _list: ListBorrow<'a, T>,
+}
+
+impl<T: Clone> Iterator for Iter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        self.next.take().map(|node| {
+            let node = node.borrow();
+            self.next = node.next.clone();
+            node.value.clone()
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T: Clone> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        self.next_back.take().map(|node| {
+            let node = node.borrow();
+            self.next_back = node.prev.clone();
+            node.value.clone()
+        })
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T: Clone> FusedIterator for Iter<'_, T> {}
+
+/// Iterator moving the values out of an owned list, returned by `List::into_iter`
+pub struct IntoIter<T> {
+    list: List<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len(), Some(self.list.len()))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+impl<T> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    /// Consumes the list, each node is freed as soon as its value has been yielded
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { list: self }
+    }
+}
+
+impl<'a, T: Clone> IntoIterator for &'a List<T> {
+    type Item = T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+/// Iterates over guards like `List::iter_mut`, which is why `T: Default` is required
+/// Each node holds `T::default()` while its guard is alive
+impl<'a, T: Default> IntoIterator for &'a mut List<T> {
+    type Item = ValueMut<'a, T>;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+/// Iterator over guards for mutating the values in place, returned by `List::iter_mut`
+pub struct IterMut<'a, T: Default> {
+    next: Option<Link<T>>,
+    next_back: Option<Link<T>>,
+    // Values left between next and next_back, so both ends stop once they meet
+    len: usize,
+    _list: ListBorrow<'a, T>,
+}
+
+impl<'a, T: Default> Iterator for IterMut<'a, T> {
+    type Item = ValueMut<'a, T>;
+
+    fn next(&mut self) -> Option<ValueMut<'a, T>> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        self.next.take().map(|node| {
+            self.next = node.borrow().next.clone();
+            ValueMut::new(node)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T: Default> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        self.next_back.take().map(|node| {
+            self.next_back = node.borrow().prev.clone();
+            ValueMut::new(node)
+        })
+    }
+}
+
+impl<T: Default> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T: Default> FusedIterator for IterMut<'_, T> {}
+
+// Holding a RefMut next to the Rc it borrows from isn't possible in safe Rust,
+// so the value is moved out of the node instead and moved back in on drop.
+// The list stays mutably borrowed meanwhile, and the node is marked so handles
+// report it as borrowed instead of reading the placeholder. The guard only keeps
+// a weak pointer, so a forgotten guard doesn't keep the node from being removed later
+/// Guard dereferencing to a single value of the list, yielded by `IterMut`
+/// The value is only written back to its node on drop, so forgetting the guard loses it
+pub struct ValueMut<'a, T: Default> {
+    node: Weak<RefCell<Node<T>>>,
+    value: T,
+    _list: ListBorrow<'a, T>,
+}
+
+impl<T: Default> ValueMut<'_, T> {
+    fn new(node: Link<T>) -> Self {
+        let value = {
+            let mut node = node.borrow_mut();
+            node.moved_out = true;
+            std::mem::take(&mut node.value)
+        };
+        ValueMut {
+            node: Rc::downgrade(&node),
+            value,
+            _list: ListBorrow(PhantomData),
+        }
+    }
+}
+
+impl<T: Default> Deref for ValueMut<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T: Default> DerefMut for ValueMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
+
+impl<T: Default> Drop for ValueMut<'_, T> {
+    fn drop(&mut self) {
+        // The list is borrowed for as long as the guard lives, so the node can't have been removed
+        if let Some(node) = self.node.upgrade() {
+            let mut node = node.borrow_mut();
+            std::mem::swap(&mut node.value, &mut self.value);
+            node.moved_out = false;
+        }
+    }
+}
+
+/// Iterator over overlapping runs of values, returned by `List::windows`
+pub struct Windows<'a, T> {
+    start: Option<Link<T>>,
+    size: usize,
+    remaining: usize,
+    _list: ListBorrow<'a, T>,
+}
+
+impl<T: Clone> Iterator for Windows<'_, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        // Unwrapping here since there are at least `size` nodes left from the start
+        let start = self.start.take().unwrap();
+        let window =
+            std::iter::successors(Some(Rc::clone(&start)), |node| node.borrow().next.clone())
+                .take(self.size)
+                .map(|node| node.borrow().value.clone())
+                .collect();
+        self.start = start.borrow().next.clone();
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for Windows<'_, T> {}
+
+impl<T: Clone> FusedIterator for Windows<'_, T> {}
+
+/// Iterator over consecutive runs of values, returned by `List::chunks`
+pub struct Chunks<'a, T> {
+    next: Option<Link<T>>,
+    size: usize,
+    // Values not yet yielded in a chunk
+    remaining: usize,
+    _list: ListBorrow<'a, T>,
+}
+
+impl<T: Clone> Iterator for Chunks<'_, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let len = self.remaining.min(self.size);
+        self.remaining -= len;
+
+        let mut chunk = Vec::with_capacity(len);
+        for _ in 0..len {
+            // Unwrapping here since there are at least `len` nodes left
+            let node = self.next.take().unwrap();
+            let node = node.borrow();
+            chunk.push(node.value.clone());
+            self.next = node.next.clone();
+        }
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let chunks = self.remaining.div_ceil(self.size.max(1));
+        (chunks, Some(chunks))
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for Chunks<'_, T> {}
+
+impl<T: Clone> FusedIterator for Chunks<'_, T> {}
+
+/// Iterator over pairs of adjacent values, returned by `List::pairs`
+pub struct Pairs<'a, T> {
+    first: Option<Link<T>>,
+    remaining: usize,
+    _list: ListBorrow<'a, T>,
+}
+
+impl<T: Clone> Iterator for Pairs<'_, T> {
+    type Item = (T, T);
+
+    fn next(&mut self) -> Option<(T, T)> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        // Unwrapping here since there are at least two nodes left
+        let first = self.first.take().unwrap();
+        let second = Rc::clone(first.borrow().next.as_ref().unwrap());
+        let pair = (first.borrow().value.clone(), second.borrow().value.clone());
+        self.first = Some(second);
+        Some(pair)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: Clone> ExactSizeIterator for Pairs<'_, T> {}
+
+impl<T: Clone> FusedIterator for Pairs<'_, T> {}
+
+/// Endless iterator over the values of a non-empty list, returned by `List::cycle_iter`
+pub struct CycleIter<'a, T> {
+    next: Option<Link<T>>,
+    head: Option<Link<T>>,
+    _list: ListBorrow<'a, T>,
+}
+
+impl<T: Clone> Iterator for CycleIter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let node = self.next.take()?;
+        let node = node.borrow();
+        // Wrap around to the head after the tail
+        self.next = node.next.clone().or_else(|| self.head.clone());
+        Some(node.value.clone())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.head {
+            Some(_) => (usize::MAX, None),
+            None => (0, Some(0)),
+        }
+    }
+}
+
+impl<T: Clone> FusedIterator for CycleIter<'_, T> {}
+
+impl<T> FromIterator<T> for List<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(values: I) -> Self {
+        let mut list = List::new();
+        for value in values {
+            let node = Node {
+                value,
+                prev: None,
+                next: None,
+                owner: Rc::clone(&list.owner),
+                moved_out: false,
+            };
+            list.push_back_link(Rc::new(RefCell::new(node)));
+        }
+        list
+    }
+}
+
+impl<T> Extend<T> for List<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, values: I) {
+        self.push_back_iter(values);
+    }
+}
+
+impl<'a, T: 'a + Copy> Extend<&'a T> for List<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, values: I) {
+        self.push_back_iter(values.into_iter().copied());
+    }
+}
+
+// Nodes are reference counted with Rc and can't be sent to other threads themselves,
+// so the values are moved or cloned into a buffer that rayon can split up instead
+#[cfg(feature = "rayon")]
+impl<T: Send> rayon::iter::IntoParallelIterator for List<T> {
+    type Iter = rayon::vec::IntoIter<T>;
+    type Item = T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.into_iter().collect::<Vec<T>>().into_par_iter()
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<T: Clone + Send> rayon::iter::IntoParallelIterator for &List<T> {
+    type Iter = rayon::vec::IntoIter<T>;
+    type Item = T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.iter().collect::<Vec<T>>().into_par_iter()
+    }
+}
+
+/// Stream moving the values out of an owned list, returned by `List::into_stream`
+#[cfg(feature = "futures")]
+pub struct IntoStream<T> {
+    list: List<T>,
+}
+
+// Every value is already there, so the stream is always ready
+#[cfg(feature = "futures")]
+impl<T> futures_core::Stream for IntoStream<T> {
+    type Item = T;
+
+    fn poll_next(
+        self: std::pin::Pin<&mut Self>,
+        _: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<T>> {
+        std::task::Poll::Ready(self.get_mut().list.pop_front())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len(), Some(self.list.len()))
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<T> futures_core::FusedStream for IntoStream<T> {
+    fn is_terminated(&self) -> bool {
+        self.list.is_empty()
+    }
+}
+
+/// Reasons a `NodeHandle` can't be used
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HandleError {
+    /// The node has been removed from its list
+    Removed,
+    /// The node is linked into a different list than the one it was used with
+    ForeignList,
+    /// The value of the node is currently borrowed mutably through a guard
+    Borrowed,
+}
+
+impl fmt::Display for HandleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            HandleError::Removed => "node has been removed from its list",
+            HandleError::ForeignList => "node belongs to a different list",
+            HandleError::Borrowed => "node value is currently borrowed",
+        })
+    }
+}
+
+impl std::error::Error for HandleError {}
+
+// Holding a strong reference would keep removed nodes from being unwrapped
+/// Handle to a single node, which stays valid no matter how the list around it changes
+pub struct NodeHandle<T> {
+    node: Weak<RefCell<Node<T>>>,
+}
+
+impl<T> NodeHandle<T> {
+    /// Create a handle to `node`, only used internally
+    fn from_link(node: &Link<T>) -> NodeHandle<T> {
+        NodeHandle {
+            node: Rc::downgrade(node),
+        }
+    }
+
+    /// Gets a clone of the value in the node
+    /// Fails if the node has been removed, or if its value is currently borrowed mutably
+    pub fn get(&self) -> Result<T, HandleError>
+    where
+        T: Clone,
+    {
+        let node = self.node.upgrade().ok_or(HandleError::Removed)?;
+        let node = node.try_borrow().map_err(|_| HandleError::Borrowed)?;
+        match node.moved_out {
+            true => Err(HandleError::Borrowed),
+            false => Ok(node.value.clone()),
+        }
+    }
+
+    /// Returns true if both handles point at the same node
+    pub fn ptr_eq(&self, other: &NodeHandle<T>) -> bool {
+        Weak::ptr_eq(&self.node, &other.node)
+    }
+
+    /// Creates an observer of the node, which has to be upgraded against a list before it can be used
+    pub fn downgrade(&self) -> WeakHandle<T> {
+        WeakHandle {
+            node: Weak::clone(&self.node),
+        }
+    }
+}
+
+// Derive would require T: Clone, only the pointer is cloned here
+impl<T> Clone for NodeHandle<T> {
+    fn clone(&self) -> Self {
+        NodeHandle {
+            node: Weak::clone(&self.node),
+        }
+    }
+}
+
+impl<T> fmt::Debug for NodeHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("NodeHandle { .. }")
+    }
+}
+
+/// Observer of a single node, which never keeps the node alive and can't edit the list by itself
+/// Unlike a `NodeHandle` it can't even read the value. Everything goes through `upgrade`, which only
+/// hands out a handle while the node is linked into the list it is given, so code holding just a
+/// `WeakHandle` can't touch a node of some other list or one that has been removed
+pub struct WeakHandle<T> {
+    node: Weak<RefCell<Node<T>>>,
+}
+
+impl<T> WeakHandle<T> {
+    /// Gets a handle to the node, but only while it is still linked into `list`
+    /// None as well while a guard writes to the value, since the node can't be checked then
+    pub fn upgrade(&self, list: &List<T>) -> Option<NodeHandle<T>> {
+        let node = self.node.upgrade()?;
+        list.owns(&node).ok()?;
+        Some(NodeHandle::from_link(&node))
+    }
+
+    /// Returns true once the node has been removed and freed
+    pub fn is_removed(&self) -> bool {
+        self.node.strong_count() == 0
+    }
+}
+
+// Derive would require T: Clone, only the pointer is cloned here
+impl<T> Clone for WeakHandle<T> {
+    fn clone(&self) -> Self {
+        WeakHandle {
+            node: Weak::clone(&self.node),
+        }
+    }
+}
+
+impl<T> fmt::Debug for WeakHandle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("WeakHandle { .. }")
+    }
+}
+
+/// Iterator over indices and node handles, returned by `List::iter_handles`
+pub struct Handles<'a, T> {
+    next: Option<Link<T>>,
+    next_back: Option<Link<T>>,
+    // Index of next, next_back is at index + len - 1
+    index: usize,
+    len: usize,
+    _list: ListBorrow<'a, T>,
+}
+
+impl<T> Iterator for Handles<'_, T> {
+    type Item = (usize, NodeHandle<T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let index = self.index;
+        self.index += 1;
+        self.len -= 1;
+        self.next.take().map(|node| {
+            self.next = node.borrow().next.clone();
+            (index, NodeHandle::from_link(&node))
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Handles<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let index = self.index + self.len;
+        self.next_back.take().map(|node| {
+            self.next_back = node.borrow().prev.clone();
+            (index, NodeHandle::from_link(&node))
+        })
+    }
+}
+
+impl<T> ExactSizeIterator for Handles<'_, T> {}
+
+impl<T> FusedIterator for Handles<'_, T> {}
+
+/// Displays the values of a list with a separator, returned by `List::format_with`
+pub struct FormatWith<'a, T, F> {
+    list: &'a List<T>,
+    sep: &'a str,
+    format: F,
+}
+
+impl<T, F> fmt::Display for FormatWith<'_, T, F>
+where
+    F: Fn(&T, &mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, node) in self.list.links().enumerate() {
+            if i > 0 {
+                f.write_str(self.sep)?;
+            }
+            (self.format)(&node.borrow().value, f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for List<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(Some(self.size))?;
+        for node in self.links() {
+            seq.serialize_element(&node.borrow().value)?;
+        }
+        seq.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for List<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ListVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: serde::Deserialize<'de>> serde::de::Visitor<'de> for ListVisitor<T> {
+            type Value = List<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a sequence")
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(
+                self,
+                mut seq: A,
+            ) -> Result<List<T>, A::Error> {
+                let mut list = List::new();
+                while let Some(value) = seq.next_element()? {
+                    list.push_back(value);
+                }
+                Ok(list)
+            }
+        }
+
+        deserializer.deserialize_seq(ListVisitor(PhantomData))
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a, T: arbitrary::Arbitrary<'a>> arbitrary::Arbitrary<'a> for List<T> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        u.arbitrary_iter()?.collect()
+    }
+
+    fn arbitrary_take_rest(u: arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        u.arbitrary_take_rest_iter()?.collect()
+    }
+}
+
+// Generating and shrinking go through Vec, which already removes elements and shrinks values
+#[cfg(feature = "quickcheck")]
+impl<T: quickcheck::Arbitrary> quickcheck::Arbitrary for List<T> {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        Vec::<T>::arbitrary(g).into()
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.to_vec().shrink().map(List::from))
+    }
+}
+
+// Like the cursors of std::collections::LinkedList, the position past the tail is a "ghost"
+// that sits in between the tail and the head, so moving past either end wraps around
+/// Position shared by both cursors, only used internally
+struct CursorPosition<T> {
+    current: Option<Link<T>>,
+    // Equals the length of the list while at the ghost position
+    index: usize,
+}
+
+impl<T> CursorPosition<T> {
+    /// Move to the next node of `list`, only used internally
+    fn move_next(&mut self, list: &List<T>) {
+        match self.current.take() {
+            Some(node) => {
+                self.current = node.borrow().next.clone();
+                self.index += 1;
+            }
+            None => {
+                self.current = list.head.clone();
+                self.index = 0;
+            }
+        }
+    }
+
+    /// Move to the previous node of `list`, only used internally
+    fn move_prev(&mut self, list: &List<T>) {
+        match self.current.take() {
+            Some(node) => {
+                self.current = node.borrow().prev.clone();
+                self.index = match self.current {
+                    Some(_) => self.index - 1,
+                    None => list.size,
+                };
+            }
+            None => {
+                self.current = list.tail.clone();
+                self.index = list.size.saturating_sub(1);
+            }
+        }
+    }
+
+    /// Index of the current node, only used internally
+    fn index(&self) -> Option<usize> {
+        self.current.as_ref().map(|_| self.index)
+    }
+
+    /// Handle to the current node, only used internally
+    fn handle(&self) -> Option<NodeHandle<T>> {
+        self.current.as_ref().map(NodeHandle::from_link)
+    }
+
+    /// Move in one direction until the current value matches `predicate`, only used internally
+    fn seek(
+        &mut self,
+        list: &List<T>,
+        forward: bool,
+        mut predicate: impl FnMut(&T) -> bool,
+    ) -> bool {
+        while let Some(node) = self.current.clone() {
+            if predicate(&node.borrow().value) {
+                return true;
+            }
+            match forward {
+                true => self.move_next(list),
+                false => self.move_prev(list),
+            }
+        }
+        false
+    }
+}
+
+// Derive would require T: Clone, only the pointer is cloned here
+impl<T> Clone for CursorPosition<T> {
+    fn clone(&self) -> Self {
+        CursorPosition {
+            current: self.current.clone(),
+            index: self.index,
+        }
+    }
+}
+
+/// Read-only cursor that can move back and forth over a list, returned by `List::cursor_front`
+pub struct Cursor<'a, T> {
+    list: &'a List<T>,
+    position: CursorPosition<T>,
+}
+
+impl<'a, T> Cursor<'a, T> {
+    /// Moves to the next value, from the last value to the ghost and from the ghost to the first value
+    pub fn move_next(&mut self) {
+        self.position.move_next(self.list);
+    }
+
+    /// Moves to the previous value, from the first value to the ghost and from the ghost to the last value
+    pub fn move_prev(&mut self) {
+        self.position.move_prev(self.list);
+    }
+
+    /// Gets a guard for reading the current value, None at the ghost position
+    pub fn current(&self) -> Option<ElementRef<'a, T>> {
+        self.position.current.clone().map(|node| ElementRef {
+            node,
+            _list: ListBorrow(PhantomData),
+        })
+    }
+
+    /// Returns the index of the current value, None at the ghost position
+    pub fn index(&self) -> Option<usize> {
+        self.position.index()
+    }
+
+    /// Returns a handle to the current node, None at the ghost position
+    pub fn handle(&self) -> Option<NodeHandle<T>> {
+        self.position.handle()
+    }
+
+    /// Moves forward until the current value matches `predicate`, checking the current value first
+    /// Returns false if no value matched, leaving the cursor at the ghost position
+    pub fn seek_forward(&mut self, predicate: impl FnMut(&T) -> bool) -> bool {
+        self.position.seek(self.list, true, predicate)
+    }
+
+    /// Moves backward until the current value matches `predicate`, checking the current value first
+    /// Returns false if no value matched, leaving the cursor at the ghost position
+    pub fn seek_backward(&mut self, predicate: impl FnMut(&T) -> bool) -> bool {
+        self.position.seek(self.list, false, predicate)
+    }
+}
+
+// Derive would require T: Clone, only the position is copied here
+impl<T> Clone for Cursor<'_, T> {
+    fn clone(&self) -> Self {
+        Cursor {
+            list: self.list,
+            position: self.position.clone(),
+        }
+    }
+}
+
+// Holds on to the borrow while a node is held, see ListBorrow
+impl<T> Drop for Cursor<'_, T> {
+    fn drop(&mut self) {}
+}
+
+/// Cursor that can insert and remove values at its position, returned by `List::cursor_front_mut`
+pub struct CursorMut<'a, T> {
+    list: &'a mut List<T>,
+    position: CursorPosition<T>,
+}
+
+impl<T> CursorMut<'_, T> {
+    /// Moves to the next value, see `Cursor::move_next`
+    pub fn move_next(&mut self) {
+        self.position.move_next(self.list);
+    }
+
+    /// Moves to the previous value, see `Cursor::move_prev`
+    pub fn move_prev(&mut self) {
+        self.position.move_prev(self.list);
+    }
+
+    /// Gets a guard for mutating the current value, None at the ghost position
+    pub fn current(&mut self) -> Option<ElementMut<'_, T>> {
+        self.position.current.clone().map(|node| ElementMut {
+            node,
+            _list: ListBorrow(PhantomData),
+        })
+    }
+
+    /// Returns the index of the current value, see `Cursor::index`
+    pub fn index(&self) -> Option<usize> {
+        self.position.index()
+    }
+
+    /// Returns a handle to the current node, see `Cursor::handle`
+    pub fn handle(&self) -> Option<NodeHandle<T>> {
+        self.position.handle()
+    }
+
+    /// Moves forward until the current value matches `predicate`, see `Cursor::seek_forward`
+    pub fn seek_forward(&mut self, predicate: impl FnMut(&T) -> bool) -> bool {
+        self.position.seek(self.list, true, predicate)
+    }
+
+    /// Moves backward until the current value matches `predicate`, see `Cursor::seek_backward`
+    pub fn seek_backward(&mut self, predicate: impl FnMut(&T) -> bool) -> bool {
+        self.position.seek(self.list, false, predicate)
+    }
+
+    /// Returns a read-only cursor at the same position, borrowing this one
+    pub fn as_cursor(&self) -> Cursor<'_, T> {
+        Cursor {
+            list: self.list,
+            position: self.position.clone(),
+        }
+    }
+
+    /// Inserts `value` in front of the current value, or at the back of the list at the ghost position
+    pub fn insert_before(&mut self, value: T) {
+        match self.position.current {
+            Some(ref node) => {
+                self.list.link_before(node, value);
+            }
+            None => {
+                self.list.push_back(value);
+            }
+        }
+        self.position.index += 1;
+    }
+
+    /// Inserts `value` after the current value, or at the front of the list at the ghost position
+    pub fn insert_after(&mut self, value: T) {
+        match self.position.current {
+            Some(ref node) => {
+                self.list.link_after(node, value);
+            }
+            None => {
+                self.list.push_front(value);
+                self.position.index = self.list.size;
+            }
+        }
+    }
+
+    /// Removes the current value and moves on to the next one
+    /// Returns None and leaves the list untouched at the ghost position
+    pub fn remove_current(&mut self) -> Option<T> {
+        let node = self.position.current.take()?;
+        self.position.current = node.borrow().next.clone();
+        self.list.unlink(&node);
+        Some(List::into_value(node))
+    }
+
+    /// Splits the list off after the current value and returns everything after it
+    /// At the ghost position the whole list is returned
+    /// Takes time linear in the smaller half, since its nodes are visited to move them to their new list
+    pub fn split_after(&mut self) -> List<T> {
+        let node = match self.position.current {
+            Some(ref node) => Rc::clone(node),
+            None => {
+                self.position.index = 0;
+                return std::mem::take(self.list);
+            }
+        };
+        let next = node.borrow().next.clone();
+        match next {
+            Some(first) => {
+                // Unwrapping here since the list holds at least the current and the next value
+                let last = self.list.tail.clone().unwrap();
+                let len = self.list.size - self.position.index - 1;
+                self.list.detach_chain(first, last, len)
+            }
+            None => List::new(),
+        }
+    }
+
+    /// Splits the list off before the current value and returns everything in front of it
+    /// At the ghost position the whole list is returned
+    /// Takes time linear in the smaller half, since its nodes are visited to move them to their new list
+    pub fn split_before(&mut self) -> List<T> {
+        let node = match self.position.current {
+            Some(ref node) => Rc::clone(node),
+            None => {
+                self.position.index = 0;
+                return std::mem::take(self.list);
+            }
+        };
+        let prev = node.borrow().prev.clone();
+        let len = self.position.index;
+        self.position.index = 0;
+        match prev {
+            Some(last) => {
+                // Unwrapping here since the list holds at least the previous and the current value
+                let first = self.list.head.clone().unwrap();
+                self.list.detach_chain(first, last, len)
+            }
+            None => List::new(),
+        }
+    }
+
+    /// Moves all values of `other` in right after the current value in constant time
+    /// At the ghost position they are moved to the front of the list
+    pub fn splice_after(&mut self, mut other: List<T>) {
+        match self.position.current {
+            Some(ref node) => self.list.link_list_after(node, other),
+            None => {
+                self.list.prepend(&mut other);
+                self.position.index = self.list.size;
+            }
+        }
+    }
+
+    /// Moves all values of `other` in right before the current value in constant time
+    /// At the ghost position they are moved to the back of the list
+    pub fn splice_before(&mut self, mut other: List<T>) {
+        let len = other.size;
+        let prev = match self.position.current {
+            Some(ref node) => node.borrow().prev.clone(),
+            None => {
+                self.list.append(&mut other);
+                self.position.index = self.list.size;
+                return;
+            }
+        };
+        match prev {
+            Some(prev) => self.list.link_list_after(&prev, other),
+            None => self.list.prepend(&mut other),
+        }
+        self.position.index += len;
+    }
+
+    /// Replaces the current value with `value` and returns the old one
+    /// Hands `value` back as an error at the ghost position
+    pub fn replace_current(&mut self, value: T) -> Result<T, T> {
+        match self.position.current {
+            Some(ref node) => Ok(std::mem::replace(&mut node.borrow_mut().value, value)),
+            None => Err(value),
+        }
+    }
+}
+
+// Holds on to the borrow while a node is held, see ListBorrow
+impl<T> Drop for CursorMut<'_, T> {
+    fn drop(&mut self) {}
+}
+
+/// Value at an index that is either there or not, returned by `List::entry`
+pub enum Entry<'a, T> {
+    Occupied(OccupiedEntry<'a, T>),
+    Vacant(VacantEntry<'a, T>),
+}
+
+impl<'a, T> Entry<'a, T> {
+    /// Inserts `value` if the entry is vacant, then returns a guard for the value in the entry
+    pub fn or_insert(self, value: T) -> ElementMut<'a, T> {
+        self.or_insert_with(|| value)
+    }
+
+    /// Inserts the result of `f` if the entry is vacant, then returns a guard for the value in the entry
+    pub fn or_insert_with(self, f: impl FnOnce() -> T) -> ElementMut<'a, T> {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(f()),
+        }
+    }
+
+    /// Inserts `T::default()` if the entry is vacant, then returns a guard for the value in the entry
+    pub fn or_default(self) -> ElementMut<'a, T>
+    where
+        T: Default,
+    {
+        self.or_insert_with(T::default)
+    }
+
+    /// Calls `f` on the value if the entry is occupied
+    pub fn and_modify(self, f: impl FnOnce(&mut T)) -> Self {
+        if let Entry::Occupied(ref entry) = self {
+            f(&mut entry.link().borrow_mut().value);
+        }
+        self
+    }
+}
+
+// The borrow of the list ends with the last use of the entry rather than when it is dropped,
+// so holding the node strongly could keep it from being unwrapped once the list removes it
+/// Entry holding a value, part of `Entry`
+pub struct OccupiedEntry<'a, T> {
+    list: &'a mut List<T>,
+    node: Weak<RefCell<Node<T>>>,
+}
+
+impl<'a, T> OccupiedEntry<'a, T> {
+    /// Get the node of the entry, only used internally
+    fn link(&self) -> Link<T> {
+        // Unwrapping here since the list is still borrowed, so the node is still linked
+        self.node.upgrade().unwrap()
+    }
+
+    /// Returns a guard for the value in the entry
+    pub fn into_mut(self) -> ElementMut<'a, T> {
+        ElementMut {
+            node: self.link(),
+            _list: ListBorrow(PhantomData),
+        }
+    }
+
+    /// Removes the value from the list and returns it
+    pub fn remove(self) -> T {
+        let node = self.link();
+        self.list.unlink(&node);
+        List::into_value(node)
+    }
+}
+
+/// Entry at the end of the list, part of `Entry`
+pub struct VacantEntry<'a, T> {
+    list: &'a mut List<T>,
+}
+
+impl<'a, T> VacantEntry<'a, T> {
+    /// Pushes `value` onto the back of the list and returns a guard for it
+    pub fn insert(self, value: T) -> ElementMut<'a, T> {
+        self.list.push_back(value);
+        ElementMut {
+            // Unwrapping here since a value was just pushed
+            node: self.list.tail.clone().unwrap(),
+            _list: ListBorrow(PhantomData),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const UPPER_BOUNDS: usize = 1000;
+
+    #[test]
+    fn test_push_back() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i as i32);
+        });
+    }
+
+    #[test]
+    fn test_len() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i as i32);
+        });
+        assert_eq!(list.len(), UPPER_BOUNDS);
+    }
+
+    #[test]
+    fn test_get() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i as i32);
+        });
+        (0..UPPER_BOUNDS).for_each(|i| assert_eq!(list.get(i), Some(i as i32)));
+    }
+
+    #[test]
+    fn test_empty_len() {
+        assert_eq!(List::<i32>::new().len(), 0);
+    }
+
+    #[test]
+    fn test_large_data() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS * 10).for_each(|i| {
+            list.push_back(i as i32);
+        });
+        assert_eq!(list.len(), UPPER_BOUNDS * 10);
+    }
+
+    #[test]
+    fn test_getting_from_large_data() {
+        let mut list: List<i32> = List::new();
+
+        (0..10000).for_each(|i| {
+            list.push_back(i);
+        });
+
+        // Not doing exhaustive testing here since get is O(n)
+        [0, 100, 2314, 3948, 231, 9489, 9999].iter().for_each(|i| {
+            assert_eq!(list.get(*i as usize), Some(*i));
+        });
+    }
+
+    #[test]
+    fn test_out_of_bounds() {
+        let mut list = List::new();
+        (0..20).for_each(|i| {
+            list.push_back(i);
+        });
+        assert_eq!(list.get(20), None);
+        assert_eq!(list.get(20 * 20), None);
+    }
+
+    #[test]
+    fn test_non_copy_type() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i.to_string());
+        });
+        assert_eq!(list.len(), UPPER_BOUNDS);
+        (0..UPPER_BOUNDS).for_each(|i| assert_eq!(list.get(i), Some(i.to_string())));
+        assert_eq!(list.get(UPPER_BOUNDS), None);
+    }
+
+    #[test]
+    fn test_push_front() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_front(i);
+        });
+        assert_eq!(list.len(), UPPER_BOUNDS);
+        (0..UPPER_BOUNDS).for_each(|i| assert_eq!(list.get(i), Some(UPPER_BOUNDS - i - 1)));
+    }
+
+    #[test]
+    fn test_push_front_and_back() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i as i32);
+            list.push_front(-(i as i32) - 1);
+        });
+        assert_eq!(list.len(), UPPER_BOUNDS * 2);
+        (0..UPPER_BOUNDS * 2).for_each(|i| {
+            assert_eq!(list.get(i), Some(i as i32 - UPPER_BOUNDS as i32));
+        });
+    }
+
+    #[test]
+    fn test_pop_back() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i);
+        });
+        (0..UPPER_BOUNDS)
+            .rev()
+            .for_each(|i| assert_eq!(list.pop_back(), Some(i)));
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.pop_back(), None);
+        assert_eq!(list.get(0), None);
+    }
+
+    #[test]
+    fn test_pop_front() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i);
+        });
+        (0..UPPER_BOUNDS).for_each(|i| assert_eq!(list.pop_front(), Some(i)));
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(list.get(0), None);
+    }
+
+    #[test]
+    fn test_pop_mixed() {
+        let mut list = List::new();
+        (0..10).for_each(|i| {
+            list.push_back(i);
+        });
+        assert_eq!(list.pop_front(), Some(0));
+        assert_eq!(list.pop_back(), Some(9));
+        assert_eq!(list.len(), 8);
+        assert_eq!(list.get(0), Some(1));
+        assert_eq!(list.get(7), Some(8));
+
+        // Reusing the list after emptying it
+        (0..8).for_each(|_| assert!(list.pop_back().is_some()));
+        list.push_front(42);
+        assert_eq!(list.get(0), Some(42));
+        assert_eq!(list.pop_back(), Some(42));
+        assert_eq!(list.pop_front(), None);
+    }
+
+    #[test]
+    fn test_pop_drops_node() {
+        let value = Rc::new(());
+        let mut list = List::new();
+        (0..3).for_each(|_| {
+            list.push_back(Rc::clone(&value));
+        });
+        assert_eq!(Rc::strong_count(&value), 4);
+
+        drop(list.pop_front());
+        drop(list.pop_back());
+        assert_eq!(Rc::strong_count(&value), 2);
+
+        drop(list.pop_back());
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn test_front_back() {
+        let mut list = List::new();
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+
+        list.push_back(1);
+        assert_eq!(list.front(), Some(1));
+        assert_eq!(list.back(), Some(1));
+
+        list.push_back(2);
+        list.push_front(0);
+        assert_eq!(list.front(), Some(0));
+        assert_eq!(list.back(), Some(2));
+
+        list.pop_front();
+        list.pop_back();
+        assert_eq!(list.front(), Some(1));
+        assert_eq!(list.back(), Some(1));
+
+        list.pop_back();
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut list = List::new();
+        assert!(list.insert(0, 1).is_ok());
+        assert!(list.insert(0, 0).is_ok());
+        assert!(list.insert(2, 3).is_ok());
+        assert!(list.insert(2, 2).is_ok());
+        assert_eq!(list.len(), 4);
+        (0..4).for_each(|i| assert_eq!(list.get(i), Some(i)));
+        assert_eq!(list.front(), Some(0));
+        assert_eq!(list.back(), Some(3));
+    }
+
+    #[test]
+    fn test_insert_middle() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i as i32);
+        });
+
+        // Near the head and near the tail, to exercise both search directions
+        assert!(list.insert(10, -1).is_ok());
+        assert!(list.insert(UPPER_BOUNDS - 10, -2).is_ok());
+        assert_eq!(list.len(), UPPER_BOUNDS + 2);
+        assert_eq!(list.get(9), Some(9));
+        assert_eq!(list.get(10), Some(-1));
+        assert_eq!(list.get(11), Some(10));
+        assert_eq!(list.get(UPPER_BOUNDS - 10), Some(-2));
+        assert_eq!(list.get(UPPER_BOUNDS - 9), Some(UPPER_BOUNDS as i32 - 11));
+
+        // Links must be intact in both directions
+        (0..list.len())
+            .rev()
+            .for_each(|_| assert!(list.pop_back().is_some()));
+        assert_eq!(list.len(), 0);
+    }
+
+    #[test]
+    fn test_insert_out_of_bounds() {
+        let mut list = List::new();
+        assert_eq!(list.insert(1, 'a').err(), Some('a'));
+        (0..5).for_each(|_| {
+            list.push_back('b');
+        });
+        assert_eq!(list.insert(6, 'a').err(), Some('a'));
+        assert_eq!(list.len(), 5);
+        assert!(list.insert(5, 'a').is_ok());
+        assert_eq!(list.back(), Some('a'));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut list = List::new();
+        (0..5).for_each(|i| {
+            list.push_back(i);
+        });
+        assert_eq!(list.remove(2), Some(2));
+        assert_eq!(list.remove(0), Some(0));
+        assert_eq!(list.remove(2), Some(4));
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.front(), Some(1));
+        assert_eq!(list.back(), Some(3));
+        assert_eq!(list.remove(2), None);
+    }
+
+    #[test]
+    fn test_remove_until_empty() {
+        // Front, middle and back
+        for pick in [|_: usize| 0, |len: usize| len / 2, |len: usize| len - 1] {
+            let mut list = List::new();
+            (0..UPPER_BOUNDS).for_each(|i| {
+                list.push_back(i);
+            });
+            let mut expected: Vec<usize> = (0..UPPER_BOUNDS).collect();
+
+            while !list.is_empty() {
+                let index = pick(list.len());
+                assert_eq!(list.remove(index), Some(expected.remove(index)));
+                assert_eq!(list.len(), expected.len());
+                assert_eq!(list.front(), expected.first().copied());
+                assert_eq!(list.back(), expected.last().copied());
+            }
+            assert_eq!(list.remove(0), None);
+            assert_eq!(list.front(), None);
+        }
+    }
+
+    #[test]
+    fn test_remove_out_of_bounds() {
+        let mut list: List<i32> = List::new();
+        assert_eq!(list.remove(0), None);
+        list.push_back(1);
+        assert_eq!(list.remove(1), None);
+        assert_eq!(list.len(), 1);
+    }
+
+    #[test]
+    fn test_is_empty() {
+        let mut list = List::new();
+        assert!(list.is_empty());
+        list.push_back(1);
+        assert!(!list.is_empty());
+        list.pop_front();
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_clear() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i);
+        });
+        list.clear();
+        assert!(list.is_empty());
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+        assert_eq!(list.get(0), None);
+
+        // The list is still usable afterwards
+        list.push_back(1);
+        list.push_front(0);
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.get(1), Some(1));
+    }
+
+    #[test]
+    fn test_clear_drops_values() {
+        let value = Rc::new(());
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|_| {
+            list.push_back(Rc::clone(&value));
+        });
+        list.clear();
+        assert_eq!(Rc::strong_count(&value), 1);
+
+        (0..UPPER_BOUNDS).for_each(|_| {
+            list.push_back(Rc::clone(&value));
+        });
+        drop(list);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn test_clear_long_list() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS * 1000).for_each(|i| {
+            list.push_back(i);
+        });
+        list.clear();
+        assert!(list.is_empty());
+
+        (0..UPPER_BOUNDS * 1000).for_each(|i| {
+            list.push_back(i);
+        });
+        drop(list);
+    }
+
+    #[test]
+    fn test_contains() {
+        let mut list = List::new();
+        assert!(!list.contains(&0));
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i);
+        });
+        assert!(list.contains(&0));
+        assert!(list.contains(&(UPPER_BOUNDS / 2)));
+        assert!(list.contains(&(UPPER_BOUNDS - 1)));
+        assert!(!list.contains(&UPPER_BOUNDS));
+    }
+
+    #[test]
+    fn test_contains_non_copy() {
+        let mut list = List::new();
+        ["a", "b", "c"].iter().for_each(|s| {
+            list.push_back(s.to_string());
+        });
+        assert!(list.contains(&"b".to_string()));
+        assert!(!list.contains(&"d".to_string()));
+    }
+
+    #[test]
+    fn test_position() {
+        let mut list = List::new();
+        assert_eq!(list.position(|_| true), None);
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i % 10);
+        });
+        assert_eq!(list.position(|v| *v == 0), Some(0));
+        assert_eq!(list.position(|v| *v > 4), Some(5));
+        assert_eq!(list.position(|v| *v == 10), None);
+    }
+
+    #[test]
+    fn test_position_stops_at_first_match() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i);
+        });
+        let mut visited = 0;
+        let found = list.position(|v| {
+            visited += 1;
+            *v == 10
+        });
+        assert_eq!(found, Some(10));
+        assert_eq!(visited, 11);
+    }
+
+    #[test]
+    fn test_index_of() {
+        let mut list = List::new();
+        ["a", "b", "c", "b"].iter().for_each(|s| {
+            list.push_back(s.to_string());
+        });
+        assert_eq!(list.index_of(&"b".to_string()), Some(1));
+        assert_eq!(list.index_of(&"c".to_string()), Some(2));
+        assert_eq!(list.index_of(&"d".to_string()), None);
+    }
+
+    #[test]
+    fn test_rposition() {
+        let mut list = List::new();
+        assert_eq!(list.rposition(|_| true), None);
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i % 10);
+        });
+        assert_eq!(list.rposition(|v| *v == 9), Some(UPPER_BOUNDS - 1));
+        assert_eq!(list.rposition(|v| *v == 0), Some(UPPER_BOUNDS - 10));
+        assert_eq!(list.rposition(|v| *v == 10), None);
+    }
+
+    #[test]
+    fn test_rposition_walks_from_tail() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i);
+        });
+        let mut visited = 0;
+        let found = list.rposition(|v| {
+            visited += 1;
+            *v == UPPER_BOUNDS - 3
+        });
+        assert_eq!(found, Some(UPPER_BOUNDS - 3));
+        assert_eq!(visited, 3);
+    }
+
+    #[test]
+    fn test_set() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i);
+        });
+        assert_eq!(list.set(0, 100), Some(0));
+        assert_eq!(list.set(UPPER_BOUNDS - 1, 200), Some(UPPER_BOUNDS - 1));
+        assert_eq!(list.set(500, 300), Some(500));
+        assert_eq!(list.set(UPPER_BOUNDS, 400), None);
+        assert_eq!(list.len(), UPPER_BOUNDS);
+        assert_eq!(list.front(), Some(100));
+        assert_eq!(list.back(), Some(200));
+        assert_eq!(list.get(500), Some(300));
+        assert_eq!(list.get(499), Some(499));
+    }
+
+    #[test]
+    fn test_update() {
+        let mut list = List::new();
+        (0..10).for_each(|i| {
+            list.push_back(vec![i]);
+        });
+        assert!(list.update(3, |v| v.push(30)));
+        assert!(list.update(9, |v| v.clear()));
+        assert!(!list.update(10, |_| panic!("should not be called")));
+        assert_eq!(list.get(3), Some(vec![3, 30]));
+        assert_eq!(list.get(9), Some(vec![]));
+        assert_eq!(list.get(4), Some(vec![4]));
+        assert_eq!(list.len(), 10);
+    }
+
+    #[test]
+    fn test_get_ref() {
+        let mut list = List::new();
+        (0..10).for_each(|i| {
+            list.push_back(vec![i; 100]);
+        });
+        let guard = list.get_ref(7).unwrap();
+        assert_eq!(guard.borrow().len(), 100);
+        assert_eq!(guard.borrow()[0], 7);
+        assert!(list.get_ref(10).is_none());
+    }
+
+    #[test]
+    fn test_get_mut() {
+        let mut list = List::new();
+        (0..10).for_each(|i| {
+            list.push_back(vec![i]);
+        });
+        {
+            let guard = list.get_mut(2).unwrap();
+            guard.borrow_mut().push(20);
+            assert_eq!(*guard.borrow(), vec![2, 20]);
+        }
+        assert_eq!(list.get(2), Some(vec![2, 20]));
+        assert!(list.get_mut(10).is_none());
+    }
+
+    #[test]
+    fn test_guards_on_different_elements() {
+        let mut list = List::new();
+        (0..10).for_each(|i| {
+            list.push_back(i);
+        });
+        let first = list.get_mut(0).unwrap();
+        let last = list.get_mut(9).unwrap();
+        let reader = list.get_ref(0).unwrap();
+        std::mem::swap(&mut *first.borrow_mut(), &mut *last.borrow_mut());
+        assert_eq!(*reader.borrow(), 9);
+        assert_eq!(list.back(), Some(0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_guard_double_mutable_borrow() {
+        let mut list = List::new();
+        list.push_back(1);
+        let guard = list.get_mut(0).unwrap();
+        let other = list.get_mut(0).unwrap();
+        let _first = guard.borrow_mut();
+        let _second = other.borrow_mut();
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i);
+        });
+        assert!(list.swap(0, UPPER_BOUNDS - 1));
+        assert!(list.swap(600, 400));
+        assert!(list.swap(5, 5));
+        assert_eq!(list.front(), Some(UPPER_BOUNDS - 1));
+        assert_eq!(list.back(), Some(0));
+        assert_eq!(list.get(400), Some(600));
+        assert_eq!(list.get(600), Some(400));
+        assert_eq!(list.get(5), Some(5));
+        assert_eq!(list.len(), UPPER_BOUNDS);
+    }
+
+    #[test]
+    fn test_swap_out_of_bounds() {
+        let mut list = List::new();
+        assert!(!list.swap(0, 0));
+        (0..5).for_each(|i| {
+            list.push_back(i);
+        });
+        assert!(!list.swap(0, 5));
+        assert!(!list.swap(5, 0));
+        (0..5).for_each(|i| assert_eq!(list.get(i), Some(i)));
+    }
+
+    #[test]
+    fn test_swap_selection_sort() {
+        let mut list = List::new();
+        [5, 3, 9, 1, 7, 2, 8].iter().for_each(|v| {
+            list.push_back(*v);
+        });
+        for i in 0..list.len() {
+            let mut min = i;
+            for j in i + 1..list.len() {
+                if list.get(j) < list.get(min) {
+                    min = j;
+                }
+            }
+            list.swap(i, min);
+        }
+        let sorted: Vec<i32> = (0..list.len()).map(|i| list.get(i).unwrap()).collect();
+        assert_eq!(sorted, vec![1, 2, 3, 5, 7, 8, 9]);
+    }
+
+    #[test]
+    fn test_remove_item() {
+        let mut list = List::new();
+        [1, 2, 3, 2, 1].iter().for_each(|v| {
+            list.push_back(*v);
+        });
+        assert!(list.remove_item(&2));
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.get(1), Some(3));
+        assert_eq!(list.get(2), Some(2));
+
+        assert!(list.remove_item(&1));
+        assert_eq!(list.front(), Some(3));
+        assert!(list.remove_item(&1));
+        assert_eq!(list.back(), Some(2));
+        assert!(!list.remove_item(&4));
+
+        assert!(list.remove_item(&3));
+        assert!(list.remove_item(&2));
+        assert!(list.is_empty());
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+    }
+
+    #[test]
+    fn test_remove_item_drops_value() {
+        let value = Rc::new(1);
+        let other = Rc::new(2);
+        let mut list = List::new();
+        list.push_back(Rc::clone(&other));
+        list.push_back(Rc::clone(&value));
+        list.push_back(Rc::clone(&other));
+        assert!(list.remove_item(&value));
+        assert_eq!(Rc::strong_count(&value), 1);
+        assert_eq!(list.len(), 2);
+    }
+
+    #[test]
+    fn test_remove_all() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i % 3);
+        });
+        let removed = list.remove_all(&0);
+        assert_eq!(removed, UPPER_BOUNDS / 3 + 1);
+        assert_eq!(list.len(), UPPER_BOUNDS - removed);
+        assert!(!list.contains(&0));
+        assert_eq!(list.front(), Some(1));
+        assert_eq!(list.back(), Some(2));
+        assert_eq!(list.remove_all(&0), 0);
+    }
+
+    #[test]
+    fn test_remove_all_every_value() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|_| {
+            list.push_back('x');
+        });
+        assert_eq!(list.remove_all(&'x'), UPPER_BOUNDS);
+        assert!(list.is_empty());
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+
+        list.push_back('y');
+        assert_eq!(list.front(), Some('y'));
+        assert_eq!(list.back(), Some('y'));
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i);
+        });
+        list.retain(|v| v % 2 == 1);
+        assert_eq!(list.len(), UPPER_BOUNDS / 2);
+        (0..list.len()).for_each(|i| assert_eq!(list.get(i), Some(i * 2 + 1)));
+        assert_eq!(list.front(), Some(1));
+        assert_eq!(list.back(), Some(UPPER_BOUNDS - 1));
+
+        list.retain(|_| true);
+        assert_eq!(list.len(), UPPER_BOUNDS / 2);
+        list.retain(|_| false);
+        assert!(list.is_empty());
+        assert_eq!(list.front(), None);
+    }
+
+    #[test]
+    fn test_retain_visits_in_order() {
+        let mut list = List::new();
+        (0..10).for_each(|i| {
+            list.push_back(i);
+        });
+        let mut visited = Vec::new();
+        let mut keep = false;
+        list.retain(|v| {
+            visited.push(*v);
+            keep = !keep;
+            keep
+        });
+        assert_eq!(visited, (0..10).collect::<Vec<_>>());
+        assert_eq!(list.len(), 5);
+        (0..5).for_each(|i| assert_eq!(list.get(i), Some(i * 2)));
+    }
+
+    #[test]
+    fn test_extract_if() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i);
+        });
+        let extracted: Vec<usize> = list.extract_if(|v| *v % 3 == 0).collect();
+        assert_eq!(extracted, (0..UPPER_BOUNDS).step_by(3).collect::<Vec<_>>());
+        assert_eq!(list.len(), UPPER_BOUNDS - extracted.len());
+        assert!(!list.contains(&0));
+        assert_eq!(list.front(), Some(1));
+        assert_eq!(list.back(), Some(UPPER_BOUNDS - 2));
+    }
+
+    #[test]
+    fn test_extract_if_is_lazy() {
+        let mut list = List::new();
+        (0..10).for_each(|i| {
+            list.push_back(i);
+        });
+        {
+            let mut extract = list.extract_if(|v| *v % 2 == 0);
+            assert_eq!(extract.next(), Some(0));
+            assert_eq!(extract.next(), Some(2));
+        }
+        // Only the values up to the last yielded one have been visited
+        assert_eq!(list.len(), 8);
+        assert_eq!(list.get(0), Some(1));
+        assert_eq!(list.get(1), Some(3));
+        assert_eq!(list.get(2), Some(4));
+    }
+
+    #[test]
+    fn test_extract_if_can_mutate() {
+        let mut list = List::new();
+        (0..10).for_each(|i| {
+            list.push_back(i);
+        });
+        let extracted: Vec<i32> = list
+            .extract_if(|v| {
+                *v *= 10;
+                *v >= 50
+            })
+            .collect();
+        assert_eq!(extracted, vec![50, 60, 70, 80, 90]);
+        (0..5).for_each(|i| assert_eq!(list.get(i), Some(i as i32 * 10)));
+        assert_eq!(list.back(), Some(40));
+    }
+
+    #[test]
+    fn test_extract_if_everything() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i.to_string());
+        });
+        assert_eq!(list.extract_if(|_| true).count(), UPPER_BOUNDS);
+        assert!(list.is_empty());
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+    }
+
+    #[test]
+    fn test_drain() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i.to_string());
+        });
+        let drained: Vec<String> = list.drain().collect();
+        assert_eq!(drained.len(), UPPER_BOUNDS);
+        (0..UPPER_BOUNDS).for_each(|i| assert_eq!(drained[i], i.to_string()));
+        assert!(list.is_empty());
+        assert_eq!(list.front(), None);
+
+        // The list is still usable afterwards
+        list.push_back("a".to_string());
+        assert_eq!(list.drain().next(), Some("a".to_string()));
+        assert_eq!(list.drain().next(), None);
+    }
+
+    #[test]
+    fn test_drain_partially_consumed() {
+        let value = Rc::new(());
+        let mut list = List::new();
+        (0..10).for_each(|_| {
+            list.push_back(Rc::clone(&value));
+        });
+        assert_eq!(list.drain().take(3).count(), 3);
+        assert!(list.is_empty());
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn test_drain_range() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i);
+        });
+        let drained: Vec<usize> = list.drain_range(100..900).collect();
+        assert_eq!(drained, (100..900).collect::<Vec<_>>());
+        assert_eq!(list.len(), 200);
+        (0..100).for_each(|i| assert_eq!(list.get(i), Some(i)));
+        (100..200).for_each(|i| assert_eq!(list.get(i), Some(i + 800)));
+    }
+
+    #[test]
+    fn test_drain_range_ends() {
+        let mut list = List::new();
+        (0..10).for_each(|i| {
+            list.push_back(i);
+        });
+        assert_eq!(list.drain_range(0..3).collect::<Vec<_>>(), vec![0, 1, 2]);
+        assert_eq!(list.front(), Some(3));
+        assert_eq!(list.drain_range(5..7).collect::<Vec<_>>(), vec![8, 9]);
+        assert_eq!(list.back(), Some(7));
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.drain_range(0..5).count(), 5);
+        assert!(list.is_empty());
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+    }
+
+    #[test]
+    fn test_drain_range_out_of_bounds() {
+        let mut list = List::new();
+        (0..10).for_each(|i| {
+            list.push_back(i);
+        });
+        assert_eq!(list.drain_range(3..3).count(), 0);
+        assert_eq!(list.drain_range(20..30).count(), 0);
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = 6..4;
+        assert_eq!(list.drain_range(reversed).count(), 0);
+        assert_eq!(list.len(), 10);
+        assert_eq!(list.drain_range(8..20).collect::<Vec<_>>(), vec![8, 9]);
+        assert_eq!(list.back(), Some(7));
+    }
+
+    #[test]
+    fn test_drain_range_partially_consumed() {
+        let mut list = List::new();
+        (0..10).for_each(|i| {
+            list.push_back(i);
+        });
+        assert_eq!(list.drain_range(2..8).next(), Some(2));
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.get(2), Some(8));
+    }
+
+    #[test]
+    fn test_truncate() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i);
+        });
+        list.truncate(UPPER_BOUNDS);
+        assert_eq!(list.len(), UPPER_BOUNDS);
+        list.truncate(UPPER_BOUNDS * 2);
+        assert_eq!(list.len(), UPPER_BOUNDS);
+
+        list.truncate(10);
+        assert_eq!(list.len(), 10);
+        assert_eq!(list.back(), Some(9));
+        assert_eq!(list.get(10), None);
+        list.push_back(100);
+        assert_eq!(list.get(10), Some(100));
+
+        list.truncate(0);
+        assert!(list.is_empty());
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+    }
+
+    #[test]
+    fn test_truncate_drops_values() {
+        let value = Rc::new(());
+        let mut list = List::new();
+        (0..UPPER_BOUNDS * 1000).for_each(|_| {
+            list.push_back(Rc::clone(&value));
+        });
+        list.truncate(1);
+        assert_eq!(Rc::strong_count(&value), 2);
+        assert_eq!(list.len(), 1);
+    }
+
+    #[test]
+    fn test_resize() {
+        let mut list = List::new();
+        list.resize(3, "a".to_string());
+        assert_eq!(list.len(), 3);
+        (0..3).for_each(|i| assert_eq!(list.get(i), Some("a".to_string())));
+
+        list.resize(5, "b".to_string());
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.get(2), Some("a".to_string()));
+        assert_eq!(list.get(3), Some("b".to_string()));
+        assert_eq!(list.back(), Some("b".to_string()));
+
+        list.resize(5, "c".to_string());
+        assert_eq!(list.len(), 5);
+        assert!(!list.contains(&"c".to_string()));
+
+        list.resize(1, "c".to_string());
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.back(), Some("a".to_string()));
+
+        list.resize(0, "c".to_string());
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_split_off() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i);
+        });
+        let mut suffix = list.split_off(UPPER_BOUNDS / 4);
+        assert_eq!(list.len(), UPPER_BOUNDS / 4);
+        assert_eq!(suffix.len(), UPPER_BOUNDS - UPPER_BOUNDS / 4);
+        (0..list.len()).for_each(|i| assert_eq!(list.get(i), Some(i)));
+        (0..suffix.len()).for_each(|i| assert_eq!(suffix.get(i), Some(i + UPPER_BOUNDS / 4)));
+        assert_eq!(list.back(), Some(UPPER_BOUNDS / 4 - 1));
+        assert_eq!(suffix.front(), Some(UPPER_BOUNDS / 4));
+
+        // Both halves are independent lists
+        list.push_back(0);
+        suffix.push_front(0);
+        assert_eq!(list.len(), UPPER_BOUNDS / 4 + 1);
+        assert_eq!(suffix.get(1), Some(UPPER_BOUNDS / 4));
+    }
+
+    #[test]
+    fn test_split_off_ends() {
+        let mut list = List::new();
+        (0..10).for_each(|i| {
+            list.push_back(i);
+        });
+        let empty = list.split_off(10);
+        assert!(empty.is_empty());
+        assert_eq!(list.len(), 10);
+        assert!(list.split_off(20).is_empty());
+
+        let everything = list.split_off(0);
+        assert!(list.is_empty());
+        assert_eq!(list.front(), None);
+        assert_eq!(everything.len(), 10);
+        assert_eq!(everything.front(), Some(0));
+        assert_eq!(everything.back(), Some(9));
+    }
+
+    #[test]
+    fn test_append() {
+        let mut list = List::new();
+        let mut other = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i);
+        });
+        (UPPER_BOUNDS..UPPER_BOUNDS * 2).for_each(|i| {
+            other.push_back(i);
+        });
+        list.append(&mut other);
+        assert_eq!(list.len(), UPPER_BOUNDS * 2);
+        (0..UPPER_BOUNDS * 2).for_each(|i| assert_eq!(list.get(i), Some(i)));
+        assert_eq!(list.back(), Some(UPPER_BOUNDS * 2 - 1));
+
+        // Links must be intact in both directions
+        assert_eq!(list.pop_back(), Some(UPPER_BOUNDS * 2 - 1));
+        assert_eq!(list.remove(UPPER_BOUNDS), Some(UPPER_BOUNDS));
+        assert_eq!(list.get(UPPER_BOUNDS), Some(UPPER_BOUNDS + 1));
+
+        assert!(other.is_empty());
+        assert_eq!(other.front(), None);
+        assert_eq!(other.back(), None);
+        other.push_back(0);
+        assert_eq!(other.len(), 1);
+    }
+
+    #[test]
+    fn test_append_empty() {
+        let mut list = List::new();
+        let mut other = List::new();
+        list.append(&mut other);
+        assert!(list.is_empty());
+
+        other.push_back(1);
+        other.push_back(2);
+        list.append(&mut other);
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.front(), Some(1));
+        assert_eq!(list.back(), Some(2));
+        assert!(other.is_empty());
+
+        list.append(&mut other);
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.back(), Some(2));
+    }
+
+    #[test]
+    fn test_prepend() {
+        let mut list = List::new();
+        let mut other = List::new();
+        (UPPER_BOUNDS..UPPER_BOUNDS * 2).for_each(|i| {
+            list.push_back(i);
+        });
+        (0..UPPER_BOUNDS).for_each(|i| {
+            other.push_back(i);
+        });
+        list.prepend(&mut other);
+        assert_eq!(list.len(), UPPER_BOUNDS * 2);
+        (0..UPPER_BOUNDS * 2).for_each(|i| assert_eq!(list.get(i), Some(i)));
+        assert_eq!(list.front(), Some(0));
+
+        // Links must be intact in both directions
+        assert_eq!(list.pop_front(), Some(0));
+        assert_eq!(list.remove(UPPER_BOUNDS - 1), Some(UPPER_BOUNDS));
+        assert_eq!(list.get(UPPER_BOUNDS - 2), Some(UPPER_BOUNDS - 1));
+
+        assert!(other.is_empty());
+        assert_eq!(other.front(), None);
+        assert_eq!(other.back(), None);
+    }
+
+    #[test]
+    fn test_prepend_empty() {
+        let mut list = List::new();
+        let mut other = List::new();
+        list.prepend(&mut other);
+        assert!(list.is_empty());
+
+        other.push_back(1);
+        other.push_back(2);
+        list.prepend(&mut other);
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.front(), Some(1));
+        assert_eq!(list.back(), Some(2));
+        assert!(other.is_empty());
+
+        list.prepend(&mut other);
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.front(), Some(1));
+    }
+
+    #[test]
+    fn test_splice() {
+        let mut list = List::new();
+        (0..10).for_each(|i| {
+            list.push_back(i);
+        });
+        let removed: Vec<i32> = list.splice(2..5, [20, 30]).collect();
+        assert_eq!(removed, vec![2, 3, 4]);
+        assert_eq!(list.len(), 9);
+        let expected = [0, 1, 20, 30, 5, 6, 7, 8, 9];
+        (0..9).for_each(|i| assert_eq!(list.get(i), Some(expected[i])));
+        assert_eq!(list.back(), Some(9));
+    }
+
+    #[test]
+    fn test_splice_insert_and_remove_only() {
+        let mut list = List::new();
+        (0..5).for_each(|i| {
+            list.push_back(i);
+        });
+
+        // Empty range only inserts
+        assert_eq!(list.splice(1..1, [10, 11]).count(), 0);
+        let expected = [0, 10, 11, 1, 2, 3, 4];
+        (0..7).for_each(|i| assert_eq!(list.get(i), Some(expected[i])));
+
+        // Empty replacement only removes
+        let removed: Vec<i32> = list.splice(1..3, []).collect();
+        assert_eq!(removed, vec![10, 11]);
+        (0..5).for_each(|i| assert_eq!(list.get(i), Some(i as i32)));
+    }
+
+    #[test]
+    fn test_splice_ends() {
+        let mut list = List::new();
+        (0..5).for_each(|i| {
+            list.push_back(i);
+        });
+        list.splice(0..1, [-1]);
+        assert_eq!(list.front(), Some(-1));
+        list.splice(4..10, [40, 50]);
+        assert_eq!(list.back(), Some(50));
+        assert_eq!(list.len(), 6);
+        list.splice(10..20, [60]);
+        assert_eq!(list.back(), Some(60));
+        let removed: Vec<i32> = list.splice(0..7, []).collect();
+        assert_eq!(removed, vec![-1, 1, 2, 3, 40, 50, 60]);
+        assert!(list.is_empty());
+        assert_eq!(list.front(), None);
+    }
+
+    #[test]
+    fn test_rotate_left() {
+        let mut list = List::new();
+        (0..10).for_each(|i| {
+            list.push_back(i);
+        });
+        list.rotate_left(3);
+        (0..10).for_each(|i| assert_eq!(list.get(i), Some((i + 3) % 10)));
+        assert_eq!(list.front(), Some(3));
+        assert_eq!(list.back(), Some(2));
+
+        list.rotate_left(17);
+        (0..10).for_each(|i| assert_eq!(list.get(i), Some(i)));
+        list.rotate_left(10);
+        (0..10).for_each(|i| assert_eq!(list.get(i), Some(i)));
+        assert_eq!(list.len(), 10);
+    }
+
+    #[test]
+    fn test_rotate_right() {
+        let mut list = List::new();
+        (0..10).for_each(|i| {
+            list.push_back(i);
+        });
+        list.rotate_right(3);
+        (0..10).for_each(|i| assert_eq!(list.get(i), Some((i + 7) % 10)));
+        assert_eq!(list.front(), Some(7));
+        assert_eq!(list.back(), Some(6));
+
+        // Links must be intact in both directions
+        assert_eq!(list.pop_back(), Some(6));
+        assert_eq!(list.pop_front(), Some(7));
+        assert_eq!(list.remove(4), Some(2));
+        assert_eq!(list.len(), 7);
+    }
+
+    #[test]
+    fn test_rotate_small() {
+        let mut list = List::new();
+        list.rotate_left(1);
+        list.rotate_right(1);
+        assert!(list.is_empty());
+
+        list.push_back(1);
+        list.rotate_left(1);
+        list.rotate_right(5);
+        assert_eq!(list.front(), Some(1));
+        assert_eq!(list.back(), Some(1));
+
+        list.push_back(2);
+        list.rotate_right(1);
+        assert_eq!(list.front(), Some(2));
+        assert_eq!(list.back(), Some(1));
+    }
+
+    #[test]
+    fn test_reverse() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i);
+        });
+        list.reverse();
+        assert_eq!(list.len(), UPPER_BOUNDS);
+        assert_eq!(list.front(), Some(UPPER_BOUNDS - 1));
+        assert_eq!(list.back(), Some(0));
+
+        // Indices close to the head and close to the tail are searched from different ends
+        (0..UPPER_BOUNDS).for_each(|i| assert_eq!(list.get(i), Some(UPPER_BOUNDS - i - 1)));
+        list.reverse();
+        (0..UPPER_BOUNDS).for_each(|i| assert_eq!(list.get(i), Some(i)));
+    }
+
+    #[test]
+    fn test_reverse_then_modify() {
+        let mut list = List::new();
+        (0..5).for_each(|i| {
+            list.push_back(i);
+        });
+        list.reverse();
+        list.push_back(-1);
+        list.push_front(5);
+        assert_eq!(list.pop_back(), Some(-1));
+        assert_eq!(list.remove(1), Some(4));
+        assert_eq!(list.pop_front(), Some(5));
+        assert_eq!(list.pop_back(), Some(0));
+        assert_eq!(list.pop_front(), Some(3));
+        assert_eq!(list.len(), 2);
+    }
+
+    #[test]
+    fn test_reverse_small() {
+        let mut list = List::new();
+        list.reverse();
+        assert!(list.is_empty());
+        list.push_back(1);
+        list.reverse();
+        assert_eq!(list.front(), Some(1));
+        assert_eq!(list.back(), Some(1));
+    }
+
+    #[test]
+    fn test_dedup() {
+        let mut list = List::new();
+        [1, 1, 2, 3, 3, 3, 1, 4, 4].iter().for_each(|v| {
+            list.push_back(*v);
+        });
+        list.dedup();
+        let expected = [1, 2, 3, 1, 4];
+        assert_eq!(list.len(), expected.len());
+        (0..expected.len()).for_each(|i| assert_eq!(list.get(i), Some(expected[i])));
+        assert_eq!(list.back(), Some(4));
+
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|_| {
+            list.push_back(7);
+        });
+        list.dedup();
+        assert_eq!(list.len(), 1);
+        assert_eq!(list.front(), Some(7));
+        assert_eq!(list.back(), Some(7));
+
+        let mut empty: List<i32> = List::new();
+        empty.dedup();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_dedup_by() {
+        let mut list = List::new();
+        ["foo", "Foo", "BAR", "bar", "baz", "Bar"]
+            .iter()
+            .for_each(|s| {
+                list.push_back(s.to_string());
+            });
+        list.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+        let expected = ["foo", "BAR", "baz", "Bar"];
+        assert_eq!(list.len(), expected.len());
+        (0..expected.len()).for_each(|i| assert_eq!(list.get(i), Some(expected[i].to_string())));
+    }
+
+    #[test]
+    fn test_dedup_by_argument_order() {
+        let mut list = List::new();
+        [10, 11, 12, 20, 21].iter().for_each(|v| {
+            list.push_back(*v);
+        });
+        let mut calls = Vec::new();
+        // Values less than 5 above the kept one are merged into it
+        list.dedup_by(|current, kept| {
+            calls.push((*current, *kept));
+            if *current - *kept < 5 {
+                *kept = *current;
+                true
+            } else {
+                false
+            }
+        });
+        assert_eq!(calls, vec![(11, 10), (12, 11), (20, 12), (21, 20)]);
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.get(0), Some(12));
+        assert_eq!(list.get(1), Some(21));
+    }
+
+    #[test]
+    fn test_insert_sorted() {
+        let mut list = List::new();
+        [5, 1, 9, 3, 7, 3, 0, 10, 5]
+            .iter()
+            .for_each(|v| list.insert_sorted(*v));
+        let expected = [0, 1, 3, 3, 5, 5, 7, 9, 10];
+        assert_eq!(list.len(), expected.len());
+        (0..expected.len()).for_each(|i| assert_eq!(list.get(i), Some(expected[i])));
+        assert_eq!(list.front(), Some(0));
+        assert_eq!(list.back(), Some(10));
+    }
+
+    #[test]
+    fn test_insert_sorted_is_stable() {
+        let mut list = List::new();
+        [(1, 'a'), (0, 'b'), (1, 'c'), (2, 'd'), (1, 'e')]
+            .iter()
+            .for_each(|v| list.insert_sorted(Pair(v.0, v.1)));
+        let expected = ['b', 'a', 'c', 'e', 'd'];
+        (0..expected.len()).for_each(|i| assert_eq!(list.get(i).unwrap().1, expected[i]));
+    }
+
+    // Only ordered by the first field, to tell equal values apart
+    #[derive(Clone, Debug)]
+    struct Pair(i32, char);
+
+    impl PartialEq for Pair {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+
+    impl Eq for Pair {}
+
+    impl PartialOrd for Pair {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Pair {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+
+    #[test]
+    fn test_insert_sorted_priority_queue() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| list.insert_sorted((i * 7919) % UPPER_BOUNDS));
+        assert_eq!(list.len(), UPPER_BOUNDS);
+        (0..UPPER_BOUNDS).for_each(|i| assert_eq!(list.pop_front(), Some(i)));
+    }
+
+    #[test]
+    fn test_search_sorted() {
+        let mut list = List::new();
+        [1, 3, 3, 5, 7].iter().for_each(|v| {
+            list.push_back(*v);
+        });
+        assert_eq!(list.search_sorted(&0), Err(0));
+        assert_eq!(list.search_sorted(&1), Ok(0));
+        assert_eq!(list.search_sorted(&2), Err(1));
+        assert_eq!(list.search_sorted(&3), Ok(1));
+        assert_eq!(list.search_sorted(&6), Err(4));
+        assert_eq!(list.search_sorted(&7), Ok(4));
+        assert_eq!(list.search_sorted(&8), Err(5));
+        assert_eq!(List::new().search_sorted(&1), Err(0));
+    }
+
+    #[test]
+    fn test_search_sorted_from() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i * 2);
+        });
+        let mut hint = list.search_hint();
+
+        // Moving forward
+        assert_eq!(list.search_sorted_from(&500, &mut hint), Ok(250));
+        assert_eq!(hint.index(), 250);
+        assert_eq!(list.search_sorted_from(&503, &mut hint), Err(252));
+        assert_eq!(hint.index(), 252);
+
+        // Moving backwards
+        assert_eq!(list.search_sorted_from(&100, &mut hint), Ok(50));
+        assert_eq!(list.search_sorted_from(&0, &mut hint), Ok(0));
+
+        // Past the end and back again
+        assert_eq!(
+            list.search_sorted_from(&(UPPER_BOUNDS * 2), &mut hint),
+            Err(UPPER_BOUNDS)
+        );
+        assert_eq!(hint.index(), UPPER_BOUNDS);
+        assert_eq!(
+            list.search_sorted_from(&(UPPER_BOUNDS * 2 - 2), &mut hint),
+            Ok(UPPER_BOUNDS - 1)
+        );
+        assert_eq!(list.search_sorted_from(&1, &mut hint), Err(1));
+    }
+
+    #[test]
+    fn test_search_sorted_from_duplicates() {
+        let mut list = List::new();
+        [1, 2, 2, 2, 3].iter().for_each(|v| {
+            list.push_back(*v);
+        });
+        let mut hint = list.search_hint();
+        assert_eq!(list.search_sorted_from(&3, &mut hint), Ok(4));
+        // Always lands on the first equal value, regardless of where the hint was
+        assert_eq!(list.search_sorted_from(&2, &mut hint), Ok(1));
+        assert_eq!(list.search_sorted_from(&2, &mut hint), Ok(1));
+        assert_eq!(list.search_sorted_from(&1, &mut hint), Ok(0));
+        assert_eq!(list.search_sorted_from(&2, &mut hint), Ok(1));
+    }
+
+    #[test]
+    fn test_search_sorted_from_other_list() {
+        let mut list = List::new();
+        (0..10).for_each(|i| {
+            list.push_back(i * 2);
+        });
+        let mut other = List::new();
+        (0..100).for_each(|i| {
+            other.push_back(i);
+        });
+        let empty = List::new();
+
+        let mut hint = other.search_hint();
+        assert_eq!(other.search_sorted_from(&50, &mut hint), Ok(50));
+        assert_eq!(list.search_sorted_from(&6, &mut hint), Ok(3));
+        assert_eq!(hint.index(), 3);
+        assert_eq!(list.search_sorted_from(&7, &mut hint), Err(4));
+
+        let mut hint = empty.search_hint();
+        assert_eq!(list.search_sorted_from(&0, &mut hint), Ok(0));
+        assert_eq!(list.search_sorted_from(&19, &mut hint), Err(10));
+    }
+
+    #[test]
+    fn test_partition() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i);
+        });
+        let (even, odd) = list.partition(|v| v % 2 == 0);
+        assert_eq!(even.len(), UPPER_BOUNDS / 2);
+        assert_eq!(odd.len(), UPPER_BOUNDS / 2);
+        (0..UPPER_BOUNDS / 2).for_each(|i| {
+            assert_eq!(even.get(i), Some(i * 2));
+            assert_eq!(odd.get(i), Some(i * 2 + 1));
+        });
+        assert_eq!(even.back(), Some(UPPER_BOUNDS - 2));
+        assert_eq!(odd.back(), Some(UPPER_BOUNDS - 1));
+    }
+
+    #[test]
+    fn test_partition_moves_values() {
+        let value = Rc::new(());
+        let mut list = List::new();
+        (0..10).for_each(|_| {
+            list.push_back(Rc::clone(&value));
+        });
+        let (all, none) = list.partition(|_| true);
+        assert_eq!(Rc::strong_count(&value), 11);
+        assert_eq!(all.len(), 10);
+        assert!(none.is_empty());
+        assert_eq!(none.front(), None);
+        drop(all);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn test_partition_then_modify() {
+        let mut list = List::new();
+        (0..10).for_each(|i| {
+            list.push_back(i);
+        });
+        let (mut small, mut large) = list.partition(|v| *v < 5);
+        small.push_back(100);
+        large.push_front(-1);
+        assert_eq!(small.pop_front(), Some(0));
+        assert_eq!(large.pop_back(), Some(9));
+        assert_eq!(small.len(), 5);
+        assert_eq!(large.len(), 5);
+        assert_eq!(large.get(1), Some(5));
+    }
+
+    #[test]
+    fn test_chunk_by() {
+        let mut list = List::new();
+        [1, 3, 2, 4, 6, 5, 8].iter().for_each(|v| {
+            list.push_back(*v);
+        });
+        let chunks = list.chunk_by(|v| v % 2);
+        let expected: [&[i32]; 4] = [&[1, 3], &[2, 4, 6], &[5], &[8]];
+        assert_eq!(chunks.len(), expected.len());
+        (0..expected.len()).for_each(|i| {
+            let chunk = chunks.get_ref(i).unwrap();
+            let chunk = chunk.borrow();
+            assert_eq!(chunk.len(), expected[i].len());
+            (0..chunk.len()).for_each(|j| assert_eq!(chunk.get(j), Some(expected[i][j])));
+        });
+        assert_eq!(list.len(), 7);
+    }
+
+    #[test]
+    fn test_chunk_by_edge_cases() {
+        let list: List<i32> = List::new();
+        assert!(list.chunk_by(|v| *v).is_empty());
+
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i);
+        });
+        assert_eq!(list.chunk_by(|_| ()).len(), 1);
+        assert_eq!(list.chunk_by(|v| *v).len(), UPPER_BOUNDS);
+        assert_eq!(list.chunk_by(|v| v / 10).len(), UPPER_BOUNDS / 10);
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut evens = List::new();
+        let mut odds = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            evens.push_back(i * 2);
+        });
+        (0..UPPER_BOUNDS / 2).for_each(|i| {
+            odds.push_back(i * 2 + 1);
+        });
+        let merged = evens.merge(odds);
+        assert_eq!(merged.len(), UPPER_BOUNDS + UPPER_BOUNDS / 2);
+        (0..UPPER_BOUNDS).for_each(|i| assert_eq!(merged.get(i), Some(i)));
+        assert_eq!(merged.get(UPPER_BOUNDS + 1), Some(UPPER_BOUNDS + 2));
+        assert_eq!(merged.back(), Some(UPPER_BOUNDS * 2 - 2));
+    }
+
+    #[test]
+    fn test_merge_is_stable() {
+        let mut first = List::new();
+        let mut second = List::new();
+        [(1, 'a'), (2, 'b'), (2, 'c')].iter().for_each(|v| {
+            first.push_back(Pair(v.0, v.1));
+        });
+        [(0, 'd'), (2, 'e'), (3, 'f')].iter().for_each(|v| {
+            second.push_back(Pair(v.0, v.1));
+        });
+        let merged = first.merge(second);
+        let expected = ['d', 'a', 'b', 'c', 'e', 'f'];
+        (0..expected.len()).for_each(|i| assert_eq!(merged.get(i).unwrap().1, expected[i]));
+    }
+
+    #[test]
+    fn test_merge_empty() {
+        let mut list = List::new();
+        (0..5).for_each(|i| {
+            list.push_back(i);
+        });
+        let list = list.merge(List::new());
+        assert_eq!(list.len(), 5);
+        let mut list = List::new().merge(list);
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.front(), Some(0));
+        assert_eq!(list.back(), Some(4));
+        assert_eq!(list.pop_back(), Some(4));
+        assert!(List::<i32>::new().merge(List::new()).is_empty());
+    }
+
+    #[test]
+    fn test_concat() {
+        let lists = (0..10).map(|i| {
+            let mut list = List::new();
+            (i * 100..(i + 1) * 100).for_each(|v| {
+                list.push_back(v);
+            });
+            list
+        });
+        let combined = List::concat(lists);
+        assert_eq!(combined.len(), UPPER_BOUNDS);
+        (0..UPPER_BOUNDS).for_each(|i| assert_eq!(combined.get(i), Some(i)));
+        assert_eq!(combined.back(), Some(UPPER_BOUNDS - 1));
+    }
+
+    #[test]
+    fn test_concat_with_empty_lists() {
+        let mut first = List::new();
+        first.push_back(1);
+        let mut second = List::new();
+        second.push_back(2);
+        second.push_back(3);
+        let mut combined = List::concat([List::new(), first, List::new(), second, List::new()]);
+        assert_eq!(combined.len(), 3);
+        assert_eq!(combined.pop_back(), Some(3));
+        assert_eq!(combined.pop_back(), Some(2));
+        assert_eq!(combined.pop_back(), Some(1));
+
+        assert!(List::<i32>::concat([]).is_empty());
+    }
+
+    #[test]
+    fn test_zip() {
+        let mut numbers = List::new();
+        let mut names = List::new();
+        (0..5).for_each(|i| {
+            numbers.push_back(i);
+        });
+        ["a", "b", "c"].iter().for_each(|s| {
+            names.push_back(s.to_string());
+        });
+        let zipped = numbers.zip(names);
+        assert_eq!(zipped.len(), 3);
+        assert_eq!(zipped.get(0), Some((0, "a".to_string())));
+        assert_eq!(zipped.get(2), Some((2, "c".to_string())));
+        assert_eq!(zipped.back(), Some((2, "c".to_string())));
+    }
+
+    #[test]
+    fn test_zip_empty() {
+        let mut list = List::new();
+        (0..5).for_each(|i| {
+            list.push_back(i);
+        });
+        assert!(list.zip(List::<char>::new()).is_empty());
+        assert!(List::<char>::new().zip(List::<char>::new()).is_empty());
+    }
+
+    #[test]
+    fn test_unzip() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back((i, i.to_string()));
+        });
+        let (numbers, strings) = list.unzip();
+        assert_eq!(numbers.len(), UPPER_BOUNDS);
+        assert_eq!(strings.len(), UPPER_BOUNDS);
+        (0..UPPER_BOUNDS).for_each(|i| {
+            assert_eq!(numbers.get(i), Some(i));
+            assert_eq!(strings.get(i), Some(i.to_string()));
+        });
+    }
+
+    #[test]
+    fn test_zip_unzip_roundtrip() {
+        let mut first = List::new();
+        let mut second = List::new();
+        (0..10).for_each(|i| {
+            first.push_back(i);
+            second.push_back(-i);
+        });
+        let (first, second) = first.zip(second).unzip();
+        (0..10).for_each(|i| {
+            assert_eq!(first.get(i as usize), Some(i));
+            assert_eq!(second.get(i as usize), Some(-i));
+        });
+        let (empty_first, empty_second) = List::<(i32, i32)>::new().unzip();
+        assert!(empty_first.is_empty());
+        assert!(empty_second.is_empty());
+    }
+
+    #[test]
+    fn test_flatten() {
+        let mut lists = List::new();
+        (0..10).for_each(|i| {
+            let mut list = List::new();
+            (0..i).for_each(|v| {
+                list.push_back(v);
+            });
+            lists.push_back(list);
+        });
+        let flat = lists.flatten();
+        assert_eq!(flat.len(), 45);
+        assert_eq!(flat.get(0), Some(0));
+        assert_eq!(flat.get(1), Some(0));
+        assert_eq!(flat.get(2), Some(1));
+        assert_eq!(flat.back(), Some(8));
+
+        assert!(List::<List<i32>>::new().flatten().is_empty());
+    }
+
+    #[test]
+    fn test_chunk_by_flatten_roundtrip() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i);
+        });
+        let flat = list.chunk_by(|v| v / 7).flatten();
+        assert_eq!(flat.len(), UPPER_BOUNDS);
+        (0..UPPER_BOUNDS).for_each(|i| assert_eq!(flat.get(i), Some(i)));
+    }
+
+    #[test]
+    fn test_push_back_iter() {
+        let mut list = List::new();
+        list.push_back(0);
+        list.push_back_iter(1..UPPER_BOUNDS);
+        assert_eq!(list.len(), UPPER_BOUNDS);
+        (0..UPPER_BOUNDS).for_each(|i| assert_eq!(list.get(i), Some(i)));
+        list.push_back_iter(std::iter::empty());
+        assert_eq!(list.len(), UPPER_BOUNDS);
+        assert_eq!(list.back(), Some(UPPER_BOUNDS - 1));
+    }
+
+    #[test]
+    fn test_extend_from_slice() {
+        let mut list = List::new();
+        list.extend_from_slice(&["a".to_string(), "b".to_string()]);
+        list.extend_from_slice(&[]);
+        list.extend_from_slice(&["c".to_string()]);
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.front(), Some("a".to_string()));
+        assert_eq!(list.get(1), Some("b".to_string()));
+        assert_eq!(list.back(), Some("c".to_string()));
+        assert_eq!(list.pop_back(), Some("c".to_string()));
+        assert_eq!(list.pop_back(), Some("b".to_string()));
+    }
+
+    #[test]
+    fn test_take() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i);
+        });
+        let mut taken = list.take();
+        assert!(list.is_empty());
+        assert_eq!(list.front(), None);
+        assert_eq!(taken.len(), UPPER_BOUNDS);
+        assert_eq!(taken.front(), Some(0));
+        assert_eq!(taken.back(), Some(UPPER_BOUNDS - 1));
+
+        // Both lists keep working independently
+        list.push_back(1);
+        taken.push_back(UPPER_BOUNDS);
+        assert_eq!(list.len(), 1);
+        assert_eq!(taken.len(), UPPER_BOUNDS + 1);
+        assert_eq!(list.take().take().take().len(), 1);
+        assert!(list.take().is_empty());
+    }
+
+    #[test]
+    fn test_swap_with() {
+        let mut front = List::new();
+        let mut back = List::new();
+        (0..10).for_each(|i| {
+            front.push_back(i);
+        });
+        front.swap_with(&mut back);
+        assert!(front.is_empty());
+        assert_eq!(back.len(), 10);
+
+        // Double buffering, filling one list while reading from the other
+        (10..15).for_each(|i| {
+            front.push_back(i);
+        });
+        front.swap_with(&mut back);
+        assert_eq!(front.len(), 10);
+        assert_eq!(front.back(), Some(9));
+        assert_eq!(back.len(), 5);
+        assert_eq!(back.front(), Some(10));
+        back.push_back(15);
+        assert_eq!(back.back(), Some(15));
+    }
+
+    #[test]
+    fn test_get_many() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i * 10);
+        });
+        let values = list.get_many(&[500, 3, UPPER_BOUNDS, 999, 3, 0]);
+        assert_eq!(
+            values,
+            vec![Some(5000), Some(30), None, Some(9990), Some(30), Some(0)]
+        );
+        assert!(list.get_many(&[]).is_empty());
+        assert_eq!(List::<i32>::new().get_many(&[0, 1]), vec![None, None]);
+    }
+
+    #[test]
+    fn test_get_many_matches_get() {
+        let mut list = List::new();
+        (0..100).for_each(|i| {
+            list.push_back(i.to_string());
+        });
+        let indices: Vec<usize> = (0..200).map(|i| (i * 37) % 150).collect();
+        let values = list.get_many(&indices);
+        indices
+            .iter()
+            .zip(values)
+            .for_each(|(i, value)| assert_eq!(value, list.get(*i)));
+    }
+
+    #[test]
+    fn test_find() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i.to_string());
+        });
+        assert_eq!(list.find(|s| s.len() == 2), Some("10".to_string()));
+        assert_eq!(list.find(|s| s.ends_with("99")), Some("99".to_string()));
+        assert_eq!(list.find(|s| s.len() == 4), None);
+        assert_eq!(List::<i32>::new().find(|_| true), None);
+    }
+
+    #[test]
+    fn test_find_from_back() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i.to_string());
+        });
+        assert_eq!(
+            list.find_from_back(|s| s.len() == 2),
+            Some("99".to_string())
+        );
+        assert_eq!(
+            list.find_from_back(|s| s.ends_with("99")),
+            Some("999".to_string())
+        );
+        assert_eq!(list.find_from_back(|s| s.len() == 4), None);
+
+        let mut visited = 0;
+        list.find_from_back(|_| {
+            visited += 1;
+            visited == 3
+        });
+        assert_eq!(visited, 3);
+    }
+
+    #[test]
+    fn test_find_map() {
+        let mut list = List::new();
+        ["a", "12", "b", "34"].iter().for_each(|s| {
+            list.push_back(s.to_string());
+        });
+        assert_eq!(list.find_map(|s| s.parse::<i32>().ok()), Some(12));
+        assert_eq!(
+            list.find_map(|s| s.strip_prefix('b').map(str::len)),
+            Some(0)
+        );
+        assert_eq!(
+            list.find_map(|s| s.parse::<f32>().ok().filter(|v| *v > 50.0)),
+            None
+        );
+        assert_eq!(List::<String>::new().find_map(|s| Some(s.len())), None);
+    }
+
+    #[test]
+    fn test_count_where() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i);
+        });
+        assert_eq!(list.count_where(|v| v % 2 == 0), UPPER_BOUNDS / 2);
+        assert_eq!(list.count_where(|v| *v < 10), 10);
+        assert_eq!(list.count_where(|_| true), UPPER_BOUNDS);
+        assert_eq!(list.count_where(|_| false), 0);
+        assert_eq!(List::<i32>::new().count_where(|_| true), 0);
+    }
+
+    #[test]
+    fn test_starts_with() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i);
+        });
+        assert!(list.starts_with(&[]));
+        assert!(list.starts_with(&[0, 1, 2]));
+        assert!(!list.starts_with(&[1, 2]));
+        assert!(!list.starts_with(&[0, 1, 3]));
+
+        let mut short = List::new();
+        short.push_back(0);
+        assert!(short.starts_with(&[0]));
+        assert!(!short.starts_with(&[0, 1]));
+        assert!(List::<i32>::new().starts_with(&[]));
+    }
+
+    #[test]
+    fn test_ends_with() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i);
+        });
+        assert!(list.ends_with(&[]));
+        assert!(list.ends_with(&[UPPER_BOUNDS - 2, UPPER_BOUNDS - 1]));
+        assert!(!list.ends_with(&[UPPER_BOUNDS - 2]));
+        assert!(!list.ends_with(&[0, UPPER_BOUNDS - 1]));
+
+        let mut short = List::new();
+        short.push_back(0);
+        assert!(short.ends_with(&[0]));
+        assert!(!short.ends_with(&[1, 0]));
+        assert!(!List::<i32>::new().ends_with(&[0]));
+    }
+
+    #[test]
+    fn test_strip_prefix() {
+        let mut list = List::new();
+        "GET /index.html".chars().for_each(|c| {
+            list.push_back(c);
+        });
+        assert!(!list.strip_prefix(&['P', 'O', 'S', 'T']));
+        assert_eq!(list.len(), 15);
+        assert!(list.strip_prefix(&['G', 'E', 'T', ' ']));
+        assert_eq!(list.len(), 11);
+        assert_eq!(list.front(), Some('/'));
+        assert!(list.strip_prefix(&[]));
+        assert_eq!(list.len(), 11);
+    }
+
+    #[test]
+    fn test_strip_suffix() {
+        let mut list = List::new();
+        "line\r\n".chars().for_each(|c| {
+            list.push_back(c);
+        });
+        assert!(!list.strip_suffix(&['\n', '\r']));
+        assert!(list.strip_suffix(&['\r', '\n']));
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.back(), Some('e'));
+        assert!(list.strip_suffix(&['l', 'i', 'n', 'e']));
+        assert!(list.is_empty());
+        assert_eq!(list.front(), None);
+        assert_eq!(list.back(), None);
+        assert!(!list.strip_suffix(&['e']));
+    }
+
+    #[test]
+    fn test_repeat() {
+        let mut list = List::new();
+        (0..3).for_each(|i| {
+            list.push_back(i);
+        });
+        let repeated = list.repeat(4);
+        assert_eq!(repeated.len(), 12);
+        (0..12).for_each(|i| assert_eq!(repeated.get(i), Some(i % 3)));
+        assert_eq!(list.len(), 3);
+
+        assert!(list.repeat(0).is_empty());
+        assert!(List::<i32>::new().repeat(10).is_empty());
+    }
+
+    #[test]
+    fn test_filled() {
+        let list = List::filled(UPPER_BOUNDS, "x".to_string());
+        assert_eq!(list.len(), UPPER_BOUNDS);
+        assert_eq!(list.count_where(|s| s == "x"), UPPER_BOUNDS);
+        assert!(List::filled(0, 1).is_empty());
+    }
+
+    #[test]
+    fn test_fill_with() {
+        let mut counter = 0;
+        let list = List::fill_with(UPPER_BOUNDS, || {
+            counter += 1;
+            counter * 2
+        });
+        assert_eq!(list.len(), UPPER_BOUNDS);
+        (0..UPPER_BOUNDS).for_each(|i| assert_eq!(list.get(i), Some((i + 1) * 2)));
+        assert_eq!(counter, UPPER_BOUNDS);
+
+        let empty = List::fill_with(0, || -> i32 { panic!("should not be called") });
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_join() {
+        let mut words = List::new();
+        ["ab", "", "c"].iter().for_each(|w| {
+            let mut word = List::new();
+            w.chars().for_each(|c| {
+                word.push_back(c);
+            });
+            words.push_back(word);
+        });
+        let joined = words.join('-');
+        let expected = ['a', 'b', '-', '-', 'c'];
+        assert_eq!(joined.len(), expected.len());
+        (0..expected.len()).for_each(|i| assert_eq!(joined.get(i), Some(expected[i])));
+        assert_eq!(joined.back(), Some('c'));
+    }
+
+    #[test]
+    fn test_join_edge_cases() {
+        assert!(List::<List<i32>>::new().join(0).is_empty());
+
+        let mut single = List::new();
+        single.push_back(List::filled(3, 1));
+        let joined = single.join(0);
+        assert_eq!(joined.len(), 3);
+        assert!(!joined.contains(&0));
+
+        let mut empties = List::new();
+        empties.push_back(List::new());
+        empties.push_back(List::new());
+        let joined = empties.join(0);
+        assert_eq!(joined.len(), 1);
+        assert_eq!(joined.front(), Some(0));
+    }
+
+    #[test]
+    fn test_iter() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i);
+        });
+        let mut expected = 0;
+        for value in list.iter() {
+            assert_eq!(value, expected);
+            expected += 1;
+        }
+        assert_eq!(expected, UPPER_BOUNDS);
+        assert_eq!(list.iter().sum::<usize>(), (0..UPPER_BOUNDS).sum::<usize>());
+        assert_eq!(list.len(), UPPER_BOUNDS);
+        assert_eq!(List::<i32>::new().iter().next(), None);
+    }
+
+    #[test]
+    fn test_iter_non_copy() {
+        let mut list = List::new();
+        ["a", "b", "c"].iter().for_each(|s| {
+            list.push_back(s.to_string());
+        });
+        let collected: Vec<String> = list.iter().collect();
+        assert_eq!(collected, vec!["a", "b", "c"]);
+        let mut iter = list.iter();
+        iter.next();
+        let rest: String = iter.collect();
+        assert_eq!(rest, "bc");
+    }
+
+    #[test]
+    fn test_into_iter() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i.to_string());
+        });
+        let mut expected = 0;
+        for value in list {
+            assert_eq!(value, expected.to_string());
+            expected += 1;
+        }
+        assert_eq!(expected, UPPER_BOUNDS);
+    }
+
+    #[test]
+    fn test_into_iter_frees_incrementally() {
+        let value = Rc::new(());
+        let mut list = List::new();
+        (0..10).for_each(|_| {
+            list.push_back(Rc::clone(&value));
+        });
+        let mut iter = list.into_iter();
+        drop(iter.next());
+        drop(iter.next());
+        assert_eq!(Rc::strong_count(&value), 9);
+        drop(iter);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn test_into_iter_collect() {
+        let mut list = List::new();
+        (0..10).for_each(|i| {
+            list.push_back(i);
+        });
+        let doubled: Vec<i32> = list.into_iter().map(|v| v * 2).collect();
+        assert_eq!(doubled, (0..10).map(|v| v * 2).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_into_iter_ref() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i);
+        });
+        let mut expected = 0;
+        for value in &list {
+            assert_eq!(value, expected);
+            expected += 1;
+        }
+        assert_eq!(expected, UPPER_BOUNDS);
+        assert_eq!(list.len(), UPPER_BOUNDS);
+
+        fn total(values: impl IntoIterator<Item = usize>) -> usize {
+            values.into_iter().sum()
+        }
+        assert_eq!(total(&list), (0..UPPER_BOUNDS).sum::<usize>());
+    }
+
+    #[test]
+    fn test_iter_rev() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i);
+        });
+        let reversed: Vec<usize> = list.iter().rev().collect();
+        assert_eq!(reversed, (0..UPPER_BOUNDS).rev().collect::<Vec<_>>());
+        assert_eq!(list.iter().rfind(|v| v % 7 == 0), Some(994));
+        let expected = (0..UPPER_BOUNDS).rev().fold(0, |acc, v| acc * 2 % 1000 + v);
+        assert_eq!(list.iter().rfold(0, |acc, v| acc * 2 % 1000 + v), expected);
+    }
+
+    #[test]
+    fn test_iter_both_ends() {
+        let mut list = List::new();
+        (0..5).for_each(|i| {
+            list.push_back(i);
+        });
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next_back(), Some(4));
+        assert_eq!(iter.next_back(), Some(3));
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(2));
+        // The ends have met
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_owning_iterators_rev() {
+        let mut list = List::new();
+        (0..10).for_each(|i| {
+            list.push_back(i);
+        });
+        let mut drain_range = list.drain_range(2..6);
+        assert_eq!(drain_range.next_back(), Some(5));
+        assert_eq!(drain_range.next(), Some(2));
+        assert_eq!(drain_range.rev().collect::<Vec<_>>(), vec![4, 3]);
+
+        let mut drain = list.drain();
+        assert_eq!(drain.next_back(), Some(9));
+        assert_eq!(drain.next(), Some(0));
+        drop(drain);
+        assert!(list.is_empty());
+
+        (0..5).for_each(|i| {
+            list.push_back(i);
+        });
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next_back(), Some(4));
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.rev().collect::<Vec<_>>(), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn test_iter_len() {
+        let mut list = List::new();
+        (0..10).for_each(|i| {
+            list.push_back(i);
+        });
+        let mut iter = list.iter();
+        assert_eq!(iter.len(), 10);
+        iter.next();
+        iter.next_back();
+        assert_eq!(iter.size_hint(), (8, Some(8)));
+        iter.by_ref().for_each(drop);
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn test_owning_iterators_len() {
+        let mut list = List::new();
+        (0..10).for_each(|i| {
+            list.push_back(i);
+        });
+
+        let mut drain_range = list.drain_range(0..4);
+        assert_eq!(drain_range.len(), 4);
+        drain_range.next_back();
+        assert_eq!(drain_range.len(), 3);
+
+        let mut drain = list.drain();
+        assert_eq!(drain.len(), 6);
+        drain.next();
+        assert_eq!(drain.len(), 5);
+        drop(drain);
+
+        (0..10).for_each(|i| {
+            list.push_back(i);
+        });
+        let mut iter = list.into_iter();
+        assert_eq!(iter.len(), 10);
+        iter.next();
+        iter.next_back();
+        assert_eq!(iter.len(), 8);
+        let collected: Vec<i32> = iter.collect();
+        assert_eq!(collected.len(), 8);
+    }
+
+    #[test]
+    fn test_extract_if_size_hint() {
+        let mut list = List::new();
+        (0..10).for_each(|i| {
+            list.push_back(i);
+        });
+        let mut extract = list.extract_if(|v| *v == 3);
+        assert_eq!(extract.size_hint(), (0, Some(10)));
+        assert_eq!(extract.next(), Some(3));
+        assert_eq!(extract.size_hint(), (0, Some(6)));
+        assert_eq!(extract.next(), None);
+        assert_eq!(extract.size_hint(), (0, Some(0)));
+        assert_eq!(extract.next(), None);
+    }
+
+    #[test]
+    fn test_for_each_mut() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i);
+        });
+        list.for_each_mut(|v| *v *= 2);
+        (0..UPPER_BOUNDS).for_each(|i| assert_eq!(list.get(i), Some(i * 2)));
+
+        let mut strings = List::new();
+        ["a", "b"].iter().for_each(|s| {
+            strings.push_back(s.to_string());
+        });
+        let mut visited = Vec::new();
+        strings.for_each_mut(|s| {
+            visited.push(s.clone());
+            s.push('!');
+        });
+        assert_eq!(visited, vec!["a", "b"]);
+        assert_eq!(strings.iter().collect::<Vec<_>>(), vec!["a!", "b!"]);
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i);
+        });
+        for mut value in list.iter_mut() {
+            *value += 1;
+        }
+        (0..UPPER_BOUNDS).for_each(|i| assert_eq!(list.get(i), Some(i + 1)));
+
+        let mut strings = List::new();
+        ["a", "b", "c"].iter().for_each(|s| {
+            strings.push_back(s.to_string());
+        });
+        strings.iter_mut().rev().enumerate().for_each(|(i, mut s)| {
+            s.push_str(&i.to_string());
+        });
+        assert_eq!(strings.iter().collect::<Vec<_>>(), vec!["a2", "b1", "c0"]);
+    }
+
+    #[test]
+    fn test_iter_mut_held_guards() {
+        let mut list = List::new();
+        (0..5).for_each(|i| {
+            list.push_back(vec![i]);
+        });
+        {
+            let mut guards: Vec<ValueMut<'_, Vec<i32>>> = list.iter_mut().collect();
+            assert_eq!(guards.len(), 5);
+            let (first, rest) = guards.split_at_mut(1);
+            first[0].append(&mut rest[3]);
+            assert_eq!(*first[0], vec![0, 4]);
+        }
+        assert_eq!(list.get(0), Some(vec![0, 4]));
+        assert_eq!(list.get(4), Some(vec![]));
+        assert_eq!(list.len(), 5);
+    }
+
+    #[test]
+    fn test_iter_mut_both_ends() {
+        let mut list = List::new();
+        (0..5).for_each(|i| {
+            list.push_back(i);
+        });
+        let mut iter = list.iter_mut();
+        assert_eq!(iter.len(), 5);
+        *iter.next().unwrap() = 10;
+        *iter.next_back().unwrap() = 40;
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.map(|v| *v).collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(list.front(), Some(10));
+        assert_eq!(list.back(), Some(40));
+    }
+
+    #[test]
+    fn test_iter_mut_forgotten_guard() {
+        let mut list = List::new();
+        let handle = list.push_back("a".to_string());
+        list.push_back("b".to_string());
+        {
+            let mut values = list.iter_mut();
+            let mut first = values.next().unwrap();
+            first.push('!');
+            std::mem::forget(first);
+        }
+        assert_eq!(list, ["", "b"]);
+        assert_eq!(handle.get(), Err(HandleError::Borrowed));
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.pop_front(), Some(String::new()));
+        assert_eq!(handle.get(), Err(HandleError::Removed));
+    }
+
+    #[test]
+    fn test_windows() {
+        let mut list = List::new();
+        (0..5).for_each(|i| {
+            list.push_back(i);
+        });
+        let windows: Vec<Vec<i32>> = list.windows(3).collect();
+        assert_eq!(windows, vec![vec![0, 1, 2], vec![1, 2, 3], vec![2, 3, 4]]);
+        assert_eq!(list.windows(3).len(), 3);
+        assert_eq!(
+            list.windows(5).collect::<Vec<_>>(),
+            vec![vec![0, 1, 2, 3, 4]]
+        );
+        assert_eq!(list.windows(1).count(), 5);
+        assert_eq!(list.windows(6).next(), None);
+        assert_eq!(list.windows(0).next(), None);
+        assert_eq!(List::<i32>::new().windows(1).next(), None);
+    }
+
+    #[test]
+    fn test_windows_moving_average() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i as f64);
+        });
+        let averages: Vec<f64> = list
+            .windows(4)
+            .map(|w| w.iter().sum::<f64>() / w.len() as f64)
+            .collect();
+        assert_eq!(averages.len(), UPPER_BOUNDS - 3);
+        averages
+            .iter()
+            .enumerate()
+            .for_each(|(i, avg)| assert_eq!(*avg, i as f64 + 1.5));
+    }
+
+    #[test]
+    fn test_chunks() {
+        let mut list = List::new();
+        (0..7).for_each(|i| {
+            list.push_back(i);
+        });
+        let chunks: Vec<Vec<i32>> = list.chunks(3).collect();
+        assert_eq!(chunks, vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]]);
+        assert_eq!(list.chunks(3).len(), 3);
+        assert_eq!(
+            list.chunks(7).collect::<Vec<_>>(),
+            vec![(0..7).collect::<Vec<_>>()]
+        );
+        assert_eq!(list.chunks(100).count(), 1);
+        assert_eq!(list.chunks(1).len(), 7);
+        assert_eq!(list.chunks(0).next(), None);
+        assert_eq!(List::<i32>::new().chunks(3).next(), None);
+    }
+
+    #[test]
+    fn test_chunks_len_updates() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i);
+        });
+        let mut chunks = list.chunks(300);
+        assert_eq!(chunks.len(), 4);
+        assert_eq!(chunks.next().map(|c| c.len()), Some(300));
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks.nth(2).map(|c| c.len()), Some(100));
+        assert_eq!(chunks.len(), 0);
+        assert_eq!(chunks.next(), None);
+    }
+
+    #[test]
+    fn test_pairs() {
+        let mut list = List::new();
+        [1, 4, 9, 16].iter().for_each(|v| {
+            list.push_back(*v);
+        });
+        let pairs: Vec<(i32, i32)> = list.pairs().collect();
+        assert_eq!(pairs, vec![(1, 4), (4, 9), (9, 16)]);
+        let deltas: Vec<i32> = list.pairs().map(|(a, b)| b - a).collect();
+        assert_eq!(deltas, vec![3, 5, 7]);
+        assert_eq!(list.pairs().len(), 3);
+
+        let mut single = List::new();
+        single.push_back(1);
+        assert_eq!(single.pairs().next(), None);
+        assert_eq!(List::<i32>::new().pairs().len(), 0);
+    }
+
+    #[test]
+    fn test_cycle_iter() {
+        let mut list = List::new();
+        ["a", "b", "c"].iter().for_each(|s| {
+            list.push_back(s.to_string());
+        });
+        let round_robin: Vec<String> = list.cycle_iter().take(7).collect();
+        assert_eq!(round_robin, vec!["a", "b", "c", "a", "b", "c", "a"]);
+        assert_eq!(list.cycle_iter().size_hint(), (usize::MAX, None));
+
+        let mut single = List::new();
+        single.push_back(1);
+        assert_eq!(
+            single.cycle_iter().take(3).collect::<Vec<_>>(),
+            vec![1, 1, 1]
+        );
+    }
+
+    #[test]
+    fn test_cycle_iter_empty() {
+        let list: List<i32> = List::new();
+        let mut cycle = list.cycle_iter();
+        assert_eq!(cycle.size_hint(), (0, Some(0)));
+        assert_eq!(cycle.next(), None);
+        assert_eq!(cycle.next(), None);
+    }
+
+    #[test]
+    fn test_iter_range() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i);
+        });
+        assert_eq!(
+            list.iter_range(10..15).collect::<Vec<_>>(),
+            vec![10, 11, 12, 13, 14]
+        );
+        assert_eq!(
+            list.iter_range(UPPER_BOUNDS - 3..UPPER_BOUNDS)
+                .rev()
+                .collect::<Vec<_>>(),
+            vec![UPPER_BOUNDS - 1, UPPER_BOUNDS - 2, UPPER_BOUNDS - 3]
+        );
+        assert_eq!(list.iter_range(0..UPPER_BOUNDS).len(), UPPER_BOUNDS);
+        assert_eq!(list.iter_range(500..500).next(), None);
+    }
+
+    #[test]
+    fn test_iter_range_out_of_bounds() {
+        let mut list = List::new();
+        (0..10).for_each(|i| {
+            list.push_back(i);
+        });
+        assert_eq!(list.iter_range(8..20).collect::<Vec<_>>(), vec![8, 9]);
+        assert_eq!(list.iter_range(20..30).len(), 0);
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = 6..4;
+        assert_eq!(list.iter_range(reversed).next(), None);
+        assert_eq!(List::<i32>::new().iter_range(0..1).next(), None);
+    }
+
+    #[test]
+    fn test_map() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i);
+        });
+        let mapped = list.map(|v| v.to_string());
+        assert_eq!(mapped.len(), UPPER_BOUNDS);
+        (0..UPPER_BOUNDS).for_each(|i| assert_eq!(mapped.get(i), Some(i.to_string())));
+        assert_eq!(list.len(), UPPER_BOUNDS);
+        assert!(List::<i32>::new().map(|v| *v).is_empty());
+    }
+
+    #[test]
+    fn test_filter() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i);
+        });
+        let filtered = list.filter(|v| v % 10 == 0);
+        assert_eq!(filtered.len(), UPPER_BOUNDS / 10);
+        (0..filtered.len()).for_each(|i| assert_eq!(filtered.get(i), Some(i * 10)));
+        assert_eq!(list.len(), UPPER_BOUNDS);
+        assert!(list.filter(|_| false).is_empty());
+    }
+
+    #[test]
+    fn test_map_in_place() {
+        let mut list = List::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            list.push_back(i.to_string());
+        });
+        list.map_in_place(|mut s| {
+            s.push('!');
+            s
+        });
+        assert_eq!(list.len(), UPPER_BOUNDS);
+        (0..UPPER_BOUNDS).for_each(|i| assert_eq!(list.get(i), Some(format!("{}!", i))));
+
+        list.map_in_place(|_| String::new());
+        assert_eq!(list.count_where(|s| s.is_empty()), UPPER_BOUNDS);
+    }
+
+    #[test]
+    fn test_map_in_place_keeps_nodes() {
+        let mut list = List::new();
+        (0..10).for_each(|i| {
+            list.push_back(i);
+        });
+        let node = list.get_link_at(5).unwrap();
+        list.map_in_place(|v| v * 3);
+        assert_eq!(node.borrow().value, 15);
+        assert_eq!(list.back(), Some(27));
+    }
+
+    #[test]
+    fn test_map_in_place_panic() {
+        let mut list = List::new();
+        (0..5).for_each(|i| {
+            list.push_back(i.to_string());
+        });
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            list.map_in_place(|mut s| {
+                if s == "2" {
+                    panic!("mapping failed");
+                }
+                s.push('!');
+                s
+            });
+        }));
+        assert!(result.is_err());
+        assert_eq!(list.len(), 5);
+        assert_eq!(list, ["0!", "1!", "", "3", "4"]);
+        list.map_in_place(|s| s + "?");
+        assert_eq!(list, ["0!?", "1!?", "?", "3?", "4?"]);
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let list: List<usize> = (0..UPPER_BOUNDS).collect();
+        assert_eq!(list.len(), UPPER_BOUNDS);
+        (0..UPPER_BOUNDS).for_each(|i| assert_eq!(list.get(i), Some(i)));
+        assert_eq!(list.front(), Some(0));
+        assert_eq!(list.back(), Some(UPPER_BOUNDS - 1));
+
+        let strings = ["a", "b"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<List<_>>();
+        assert_eq!(strings.iter().collect::<Vec<_>>(), vec!["a", "b"]);
+        assert!(std::iter::empty::<i32>().collect::<List<_>>().is_empty());
+    }
+
+    #[test]
+    fn test_from_iter_links_both_ways() {
+        let mut list: List<i32> = (0..10).collect();
+        assert_eq!(
+            list.iter().rev().collect::<Vec<_>>(),
+            (0..10).rev().collect::<Vec<_>>()
+        );
+        assert_eq!(list.pop_back(), Some(9));
+        assert_eq!(list.remove(4), Some(4));
+        list.push_back(10);
+        assert_eq!(
+            list.iter().collect::<Vec<_>>(),
+            vec![0, 1, 2, 3, 5, 6, 7, 8, 10]
+        );
+    }
+
+    #[test]
+    fn test_extend() {
+        let mut list: List<i32> = (0..5).collect();
+        list.extend(5..10);
+        list.extend(&[10, 11]);
+        list.extend([12].iter());
+        list.extend(std::iter::empty::<i32>());
+        assert_eq!(list.len(), 13);
+        assert_eq!(list.iter().collect::<Vec<_>>(), (0..13).collect::<Vec<_>>());
+
+        fn fill<C: Extend<String>>(collection: &mut C) {
+            collection.extend(["x".to_string(), "y".to_string()]);
+        }
+        let mut strings = List::new();
+        fill(&mut strings);
+        assert_eq!(strings.iter().collect::<Vec<_>>(), vec!["x", "y"]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_into_par_iter() {
+        use rayon::prelude::*;
+
+        let list: List<u64> = (0..UPPER_BOUNDS as u64).collect();
+        let squares: Vec<u64> = (&list).into_par_iter().map(|v| v * v).collect();
+        assert_eq!(
+            squares,
+            (0..UPPER_BOUNDS as u64).map(|v| v * v).collect::<Vec<_>>()
+        );
+        assert_eq!(list.len(), UPPER_BOUNDS);
+
+        let even_sum: u64 = list.into_par_iter().filter(|v| v % 2 == 0).sum();
+        assert_eq!(
+            even_sum,
+            (0..UPPER_BOUNDS as u64).filter(|v| v % 2 == 0).sum::<u64>()
+        );
+    }
+
+    #[cfg(feature = "futures")]
+    #[test]
+    fn test_into_stream() {
+        use futures::executor::block_on;
+        use futures::stream::{Stream, StreamExt};
+
+        let list: List<usize> = (0..UPPER_BOUNDS).collect();
+        let stream = list.into_stream();
+        assert_eq!(stream.size_hint(), (UPPER_BOUNDS, Some(UPPER_BOUNDS)));
+        let doubled: Vec<usize> = block_on(stream.map(|v| v * 2).collect());
+        assert_eq!(
+            doubled,
+            (0..UPPER_BOUNDS).map(|v| v * 2).collect::<Vec<_>>()
+        );
+
+        let mut empty = List::<i32>::new().into_stream();
+        assert_eq!(block_on(empty.next()), None);
+    }
+
+    #[cfg(feature = "futures")]
+    #[test]
+    fn test_into_stream_consumer() {
+        use futures::executor::block_on;
+        use futures::stream::StreamExt;
+
+        let list: List<String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+        let mut stream = list.into_stream();
+        let consumer = async {
+            let mut received = String::new();
+            while let Some(value) = stream.next().await {
+                received.push_str(&value);
+            }
+            received
+        };
+        assert_eq!(block_on(consumer), "abc");
+    }
+
+    #[test]
+    fn test_iter_handles() {
+        let list: List<String> = (0..10).map(|i| i.to_string()).collect();
+        let handles: Vec<(usize, NodeHandle<String>)> = list.iter_handles().collect();
+        assert_eq!(handles.len(), 10);
+        handles
+            .iter()
+            .for_each(|(i, handle)| assert_eq!(handle.get(), Ok(i.to_string())));
+
+        let interesting: Vec<usize> = list
+            .iter_handles()
+            .filter(|(_, handle)| handle.get().unwrap().parse::<i32>().unwrap() % 3 == 0)
+            .map(|(i, _)| i)
+            .collect();
+        assert_eq!(interesting, vec![0, 3, 6, 9]);
+        assert_eq!(
+            List::<i32>::new().iter_handles().next().map(|(i, _)| i),
+            None
+        );
+    }
+
+    #[test]
+    fn test_iter_handles_rev() {
+        let list: List<i32> = (0..5).collect();
+        let mut handles = list.iter_handles();
+        assert_eq!(handles.len(), 5);
+        let (back_index, back) = handles.next_back().unwrap();
+        assert_eq!((back_index, back.get()), (4, Ok(4)));
+        let (front_index, front) = handles.next().unwrap();
+        assert_eq!((front_index, front.get()), (0, Ok(0)));
+        let rest: Vec<(usize, i32)> = handles.rev().map(|(i, h)| (i, h.get().unwrap())).collect();
+        assert_eq!(rest, vec![(3, 3), (2, 2), (1, 1)]);
+    }
+
+    #[test]
+    fn test_handle_to_removed_node() {
+        let mut list: List<i32> = (0..3).collect();
+        let handles: Vec<NodeHandle<i32>> = list.iter_handles().map(|(_, h)| h).collect();
+        assert_eq!(list.pop_front(), Some(0));
+        assert_eq!(list.remove(1), Some(2));
+        assert_eq!(handles[0].get(), Err(HandleError::Removed));
+        assert_eq!(handles[1].get(), Ok(1));
+        assert_eq!(handles[2].get(), Err(HandleError::Removed));
+    }
+
+    #[test]
+    fn test_handle_survives_list_changes() {
+        let mut list: List<i32> = (0..5).collect();
+        let (_, handle) = list.iter_handles().nth(2).unwrap();
+        let same = handle.clone();
+        list.push_front(-1);
+        list.set(3, 20);
+        list.reverse();
+        assert_eq!(handle.get(), Ok(20));
+        assert!(handle.ptr_eq(&same));
+        let (_, other) = list.iter_handles().next().unwrap();
+        assert!(!handle.ptr_eq(&other));
+    }
+
+    #[test]
+    fn test_drain_back() {
+        let mut list: List<usize> = (0..UPPER_BOUNDS).collect();
+        let drained: Vec<usize> = list.drain_back().collect();
+        assert_eq!(drained, (0..UPPER_BOUNDS).rev().collect::<Vec<_>>());
+        assert!(list.is_empty());
+        assert_eq!(list.drain_back().next(), None);
+    }
+
+    #[test]
+    fn test_drain_back_frees_incrementally() {
+        let values: Vec<Rc<i32>> = (0..5).map(Rc::new).collect();
+        let mut list: List<Rc<i32>> = values.iter().cloned().collect();
+        let mut drain = list.drain_back();
+        assert_eq!(drain.next().map(|v| *v), Some(4));
+        assert_eq!(Rc::strong_count(&values[4]), 1);
+        assert_eq!(Rc::strong_count(&values[3]), 2);
+        assert_eq!(drain.len(), 4);
+        drop(drain);
+        assert!(list.is_empty());
+        values
+            .iter()
+            .for_each(|v| assert_eq!(Rc::strong_count(v), 1));
+    }
+
+    #[test]
+    fn test_default() {
+        #[derive(Default)]
+        struct Holder {
+            list: List<i32>,
+        }
+
+        let mut holder = Holder::default();
+        assert!(holder.list.is_empty());
+        holder.list.push_back(1);
+        holder.list.push_back(2);
+        let taken = std::mem::take(&mut holder.list);
+        assert!(holder.list.is_empty());
+        assert_eq!(taken.len(), 2);
+        assert_eq!(taken.front(), Some(1));
+    }
+
+    #[test]
+    fn test_clone() {
+        let original: List<String> = (0..UPPER_BOUNDS).map(|i| i.to_string()).collect();
+        let mut copy = original.clone();
+        assert_eq!(copy.len(), UPPER_BOUNDS);
+        (0..UPPER_BOUNDS).for_each(|i| assert_eq!(copy.get(i), Some(i.to_string())));
+
+        copy.set(0, "changed".to_string());
+        copy.push_back("extra".to_string());
+        copy.pop_front();
+        copy.reverse();
+        assert_eq!(original.len(), UPPER_BOUNDS);
+        assert_eq!(original.front(), Some("0".to_string()));
+        assert_eq!(original.back(), Some((UPPER_BOUNDS - 1).to_string()));
+        (0..UPPER_BOUNDS).for_each(|i| assert_eq!(original.get(i), Some(i.to_string())));
+    }
+
+    #[test]
+    fn test_clone_does_not_share_nodes() {
+        let original: List<i32> = (0..5).collect();
+        let copy = original.clone();
+        copy.get_mut(2).unwrap().borrow_mut().clone_from(&20);
+        assert_eq!(copy.get(2), Some(20));
+        assert_eq!(original.get(2), Some(2));
+
+        let empty: List<i32> = List::new();
+        assert!(empty.clone().is_empty());
+    }
+
+    #[test]
+    fn test_clone_long_list() {
+        let original: List<usize> = (0..100_000).collect();
+        let copy = original.clone();
+        assert_eq!(copy.len(), original.len());
+        assert_eq!(copy.back(), Some(99_999));
+    }
+
+    #[test]
+    fn test_debug() {
+        let list: List<i32> = (1..4).collect();
+        assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+        assert_eq!(format!("{:?}", List::<i32>::new()), "[]");
+        let strings: List<&str> = ["a", "b"].into_iter().collect();
+        assert_eq!(format!("{:?}", strings), "[\"a\", \"b\"]");
+        assert_eq!(format!("{:#?}", strings), "[\n    \"a\",\n    \"b\",\n]");
+    }
+
+    #[test]
+    fn test_display() {
+        let list: List<&str> = ["a", "b", "c"].into_iter().collect();
+        assert_eq!(list.to_string(), "[a, b, c]");
+        assert_eq!(List::<i32>::new().to_string(), "[]");
+        let single: List<i32> = std::iter::once(7).collect();
+        assert_eq!(format!("{}", single), "[7]");
+    }
+
+    #[test]
+    fn test_format_with() {
+        use std::fmt::Write as _;
+        use std::io::Write as _;
+
+        let list: List<i32> = (1..5).collect();
+        let mut text = String::new();
+        write!(
+            text,
+            "{}",
+            list.format_with(" -> ", |v, f| write!(f, "<{}>", v))
+        )
+        .unwrap();
+        assert_eq!(text, "<1> -> <2> -> <3> -> <4>");
+
+        let mut bytes: Vec<u8> = Vec::new();
+        write!(
+            bytes,
+            "{}",
+            list.format_with("", |v, f| write!(f, "{:02}", v * 3))
+        )
+        .unwrap();
+        assert_eq!(bytes, b"03060912");
+
+        let empty: List<i32> = List::new();
+        assert_eq!(
+            empty
+                .format_with(", ", |v, f| write!(f, "{}", v))
+                .to_string(),
+            ""
+        );
+    }
+
+    #[test]
+    fn test_eq() {
+        let a: List<i32> = (0..UPPER_BOUNDS as i32).collect();
+        let mut b = a.clone();
+        assert_eq!(a, b);
+        assert_eq!(a, a);
+        b.set(UPPER_BOUNDS - 1, -1);
+        assert_ne!(a, b);
+        b.pop_back();
+        assert_ne!(a, b);
+        assert_eq!(List::<i32>::new(), List::new());
+    }
+
+    #[test]
+    fn test_eq_length_first() {
+        // The values are never compared when the lengths differ
+        #[derive(Debug)]
+        struct Panicky;
+        impl PartialEq for Panicky {
+            fn eq(&self, _: &Self) -> bool {
+                panic!("values compared")
+            }
+        }
+
+        let one: List<Panicky> = std::iter::once(Panicky).collect();
+        let two: List<Panicky> = [Panicky, Panicky].into_iter().collect();
+        assert!(one != two);
+    }
+
+    #[test]
+    fn test_eq_sequences() {
+        let list: List<i32> = (1..4).collect();
+        assert_eq!(list, vec![1, 2, 3]);
+        assert_eq!(list, [1, 2, 3]);
+        assert_eq!(list, &[1, 2, 3][..]);
+        assert_eq!(list, *vec![1, 2, 3].as_slice());
+        assert_ne!(list, vec![1, 2]);
+        assert_ne!(list, [1, 2, 4]);
+        assert_ne!(list, [1, 2, 3, 4]);
+        assert_eq!(List::<i32>::new(), [0; 0]);
+
+        let strings: List<String> = ["a", "b"].into_iter().map(String::from).collect();
+        assert_eq!(strings, ["a", "b"]);
+    }
+
+    #[test]
+    fn test_ord() {
+        use std::cmp::Ordering;
+
+        let list = |values: &[i32]| values.iter().copied().collect::<List<i32>>();
+        assert!(list(&[1, 2, 3]) < list(&[1, 2, 4]));
+        assert!(list(&[1, 2]) < list(&[1, 2, 0]));
+        assert!(list(&[2]) > list(&[1, 9, 9]));
+        assert!(List::new() < list(&[0]));
+        assert_eq!(list(&[1, 2]).cmp(&list(&[1, 2])), Ordering::Equal);
+        [vec![3, 1], vec![], vec![1, 2, 3], vec![1, 2], vec![3]]
+            .iter()
+            .for_each(|a| {
+                let b = vec![1, 2, 3];
+                assert_eq!(list(a).cmp(&list(&b)), a.cmp(&b));
+            });
+
+        // get_mut works through a shared reference, the keys are just never touched here
+        #[allow(clippy::mutable_key_type)]
+        let set: std::collections::BTreeSet<List<i32>> =
+            [list(&[2]), list(&[1, 5]), list(&[1]), list(&[2])]
+                .into_iter()
+                .collect();
+        let sorted: Vec<List<i32>> = set.into_iter().collect();
+        assert_eq!(sorted, vec![list(&[1]), list(&[1, 5]), list(&[2])]);
+    }
+
+    #[test]
+    fn test_partial_ord() {
+        let a: List<f64> = [1.0, f64::NAN].into_iter().collect();
+        let b: List<f64> = [1.0, 2.0].into_iter().collect();
+        let c: List<f64> = [0.5, f64::NAN].into_iter().collect();
+        assert_eq!(a.partial_cmp(&b), None);
+        assert!(c < a);
+    }
+
+    #[test]
+    fn test_hash() {
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        let hash = |list: &List<List<i32>>| {
+            let mut hasher = DefaultHasher::new();
+            list.hash(&mut hasher);
+            hasher.finish()
+        };
+        let nested = |parts: &[&[i32]]| {
+            parts
+                .iter()
+                .map(|part| part.iter().copied().collect::<List<i32>>())
+                .collect::<List<List<i32>>>()
+        };
+        assert_eq!(
+            hash(&nested(&[&[1, 2], &[3]])),
+            hash(&nested(&[&[1, 2], &[3]]))
+        );
+        assert_ne!(
+            hash(&nested(&[&[1, 2], &[3]])),
+            hash(&nested(&[&[1], &[2, 3]]))
+        );
+        assert_ne!(hash(&nested(&[&[], &[1]])), hash(&nested(&[&[1], &[]])));
+
+        #[allow(clippy::mutable_key_type)]
+        let mut counts: std::collections::HashMap<List<i32>, usize> = Default::default();
+        [vec![1, 2], vec![2, 1], vec![1, 2], vec![]]
+            .into_iter()
+            .for_each(|values| {
+                *counts.entry(values.into_iter().collect()).or_default() += 1;
+            });
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts[&[1, 2].into_iter().collect::<List<i32>>()], 2);
+        assert_eq!(counts[&List::new()], 1);
+    }
+
+    #[test]
+    fn test_at() {
+        let list: List<String> = (0..10).map(|i| i.to_string()).collect();
+        assert_eq!(*list.at(3).borrow(), "3");
+        list.at_mut(3).borrow_mut().push('!');
+        assert_eq!(*list.at(3).borrow(), "3!");
+        assert_eq!(list.get(3), Some("3!".to_string()));
+        assert_eq!(list.get(10), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 3 but the index is 3")]
+    fn test_at_out_of_bounds() {
+        let list: List<i32> = (0..3).collect();
+        list.at(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds: the len is 0 but the index is 0")]
+    fn test_at_mut_out_of_bounds() {
+        let list: List<i32> = List::new();
+        list.at_mut(0);
+    }
+
+    #[test]
+    fn test_vec_conversions() {
+        let list = List::from(vec![1, 2, 3]);
+        assert_eq!(list, [1, 2, 3]);
+        assert_eq!(list.to_vec(), vec![1, 2, 3]);
+        assert_eq!(list.len(), 3);
+
+        let strings = ["a".to_string(), "b".to_string()];
+        let from_slice = List::from(&strings[..]);
+        assert_eq!(from_slice, strings);
+
+        let back: Vec<String> = from_slice.into();
+        assert_eq!(back, strings);
+        assert_eq!(Vec::from(List::<i32>::new()), Vec::<i32>::new());
+
+        let large: Vec<usize> = (0..UPPER_BOUNDS).collect();
+        assert_eq!(Vec::from(List::from(large.clone())), large);
+    }
+
+    #[test]
+    fn test_std_linked_list_conversions() {
+        let mut std_list = std::collections::LinkedList::new();
+        (0..UPPER_BOUNDS).for_each(|i| {
+            std_list.push_front(i);
+        });
+        let list = List::from(std_list.clone());
+        assert_eq!(list.len(), UPPER_BOUNDS);
+        assert_eq!(list.front(), Some(UPPER_BOUNDS - 1));
+        assert_eq!(list.back(), Some(0));
+
+        let back: std::collections::LinkedList<usize> = list.into();
+        assert_eq!(back, std_list);
+        assert!(List::from(std::collections::LinkedList::<i32>::new()).is_empty());
+    }
+
+    #[test]
+    fn test_vec_deque_conversions() {
+        let mut deque = std::collections::VecDeque::new();
+        (0..10).for_each(|i| {
+            deque.push_front(i);
+        });
+        (10..20).for_each(|i| {
+            deque.push_back(i);
+        });
+        let list = List::from(deque.clone());
+        assert_eq!(list, Vec::from(deque.clone()));
+
+        let back: std::collections::VecDeque<i32> = list.into();
+        assert_eq!(back, deque);
+        assert!(List::from(std::collections::VecDeque::<i32>::new()).is_empty());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_json() {
+        let list: List<String> = ["a", "b", "c"].into_iter().map(String::from).collect();
+        let json = serde_json::to_string(&list).unwrap();
+        assert_eq!(json, r#"["a","b","c"]"#);
+        let back: List<String> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, list);
+
+        let empty: List<i32> = List::new();
+        assert_eq!(serde_json::to_string(&empty).unwrap(), "[]");
+        assert!(serde_json::from_str::<List<i32>>("[]").unwrap().is_empty());
+        assert!(serde_json::from_str::<List<i32>>(r#"{"a": 1}"#).is_err());
+        assert!(serde_json::from_str::<List<i32>>(r#"[1, "b"]"#).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_json_large() {
+        let list: List<usize> = (0..100_000).collect();
+        let json = serde_json::to_string(&list).unwrap();
+        let back: List<usize> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.len(), 100_000);
+        assert_eq!(back, list);
+
+        let nested: List<List<i32>> = (0..10).map(|i| (0..i).collect()).collect();
+        let json = serde_json::to_string(&nested).unwrap();
+        assert_eq!(
+            serde_json::from_str::<List<List<i32>>>(&json).unwrap(),
+            nested
+        );
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn test_arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let bytes: Vec<u8> = (0..=255).cycle().take(4096).collect();
+        let list = List::<u32>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        let vec = Vec::<u32>::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+        assert_eq!(list, vec);
+
+        let rest = List::<u16>::arbitrary_take_rest(Unstructured::new(&bytes)).unwrap();
+        assert_eq!(
+            rest,
+            Vec::<u16>::arbitrary_take_rest(Unstructured::new(&bytes)).unwrap()
+        );
+        assert!(List::<u8>::arbitrary(&mut Unstructured::new(&[]))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_list_macro() {
+        let list: List<i32> = list![1, 2, 3];
+        assert_eq!(list, [1, 2, 3]);
+        assert_eq!(list![1, 2, 3,], [1, 2, 3]);
+        assert_eq!(list!["a".to_string(); 3], ["a", "a", "a"]);
+        assert!(list![0; 0].is_empty());
+        let order = RefCell::new(Vec::new());
+        let filled =
+            list![{ order.borrow_mut().push("value"); 7 }; { order.borrow_mut().push("len"); 2 }];
+        assert_eq!(filled, [7, 7]);
+        assert_eq!(order.into_inner(), ["value", "len"]);
+        let empty: List<i32> = list![];
+        assert!(empty.is_empty());
+        let nested: List<List<i32>> = list![list![1], list![], list![2, 3]];
+        assert_eq!(nested.len(), 3);
+        assert_eq!(nested.back(), Some(list![2, 3]));
+    }
+
+    #[test]
+    fn test_into_iter_mut_ref() {
+        let mut list: List<i32> = (0..UPPER_BOUNDS as i32).collect();
+        for mut value in &mut list {
+            *value += 1;
+        }
+        (0..UPPER_BOUNDS).for_each(|i| assert_eq!(list.get(i), Some(i as i32 + 1)));
+
+        let mut strings: List<String> = list!["a".to_string(), "b".to_string()];
+        for mut value in (&mut strings).into_iter().rev() {
+            value.push('!');
+        }
+        assert_eq!(strings, ["a!", "b!"]);
+    }
+
+    #[cfg(feature = "quickcheck")]
+    #[test]
+    fn test_quickcheck_properties() {
+        fn reverse_twice(list: List<i32>) -> bool {
+            let mut reversed = list.clone();
+            reversed.reverse();
+            reversed.reverse();
+            reversed == list
+        }
+
+        fn matches_vec(list: List<i32>) -> bool {
+            let vec = list.to_vec();
+            list.len() == vec.len() && list == vec
+        }
+
+        quickcheck::quickcheck(reverse_twice as fn(List<i32>) -> bool);
+        quickcheck::quickcheck(matches_vec as fn(List<i32>) -> bool);
+    }
+
+    #[cfg(feature = "quickcheck")]
+    #[test]
+    fn test_quickcheck_shrink() {
+        use quickcheck::Arbitrary;
+
+        let list: List<u32> = list![5, 10, 20];
+        let shrunk: Vec<List<u32>> = list.shrink().collect();
+        assert!(!shrunk.is_empty());
+        assert!(shrunk.iter().all(|smaller| smaller.len() <= list.len()));
+        assert!(shrunk.iter().any(|smaller| smaller.len() < list.len()));
+        assert!(shrunk
+            .iter()
+            .any(|smaller| smaller.len() == list.len() && *smaller < list));
+        assert_eq!(List::<u32>::new().shrink().count(), 0);
+    }
+
+    #[test]
+    fn test_cursor() {
+        let list: List<i32> = (0..5).collect();
+        let mut cursor = list.cursor_front();
+        assert_eq!(cursor.index(), Some(0));
+        assert_eq!(*cursor.current().unwrap().borrow(), 0);
+        (1..5).for_each(|i| {
+            cursor.move_next();
+            assert_eq!(cursor.index(), Some(i));
+            assert_eq!(*cursor.current().unwrap().borrow(), i as i32);
+        });
+
+        // Backtracking from the middle
+        cursor.move_prev();
+        cursor.move_prev();
+        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(*cursor.current().unwrap().borrow(), 2);
+
+        let mut back = list.cursor_back();
+        assert_eq!(back.index(), Some(4));
+        assert_eq!(*back.current().unwrap().borrow(), 4);
+        back.move_prev();
+        assert_eq!(*back.current().unwrap().borrow(), 3);
+        assert_eq!(cursor.clone().index(), Some(2));
+    }
+
+    #[test]
+    fn test_cursor_ghost() {
+        let list: List<i32> = (0..3).collect();
+        let mut cursor = list.cursor_back();
+        cursor.move_next();
+        assert_eq!(cursor.index(), None);
+        assert!(cursor.current().is_none());
+        cursor.move_next();
+        assert_eq!(cursor.index(), Some(0));
+
+        cursor.move_prev();
+        assert_eq!(cursor.index(), None);
+        cursor.move_prev();
+        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(*cursor.current().unwrap().borrow(), 2);
+
+        let empty: List<i32> = List::new();
+        let mut cursor = empty.cursor_front();
+        assert_eq!(cursor.index(), None);
+        cursor.move_next();
+        cursor.move_prev();
+        assert!(cursor.current().is_none());
+        assert_eq!(empty.cursor_back().index(), None);
+    }
+
+    #[test]
+    fn test_cursor_walk() {
+        let list: List<usize> = (0..UPPER_BOUNDS).collect();
+        let mut cursor = list.cursor_front();
+        let mut visited = 0;
+        while let Some(value) = cursor.current() {
+            assert_eq!(*value.borrow(), cursor.index().unwrap());
+            visited += 1;
+            cursor.move_next();
+        }
+        assert_eq!(visited, UPPER_BOUNDS);
+    }
+
+    #[test]
+    fn test_cursor_mut_insert() {
+        let mut list: List<i32> = list![1, 3];
+        let mut cursor = list.cursor_front_mut();
+        cursor.insert_before(0);
+        assert_eq!(cursor.index(), Some(1));
+        cursor.insert_after(2);
+        assert_eq!(cursor.index(), Some(1));
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(*cursor.current().unwrap().borrow(), 3);
+        assert_eq!(cursor.index(), Some(3));
+
+        // At the ghost position values go to the ends of the list
+        cursor.move_next();
+        cursor.insert_before(4);
+        cursor.insert_after(-1);
+        assert_eq!(cursor.index(), None);
+        cursor.move_prev();
+        assert_eq!(cursor.index(), Some(5));
+        assert_eq!(*cursor.current().unwrap().borrow(), 4);
+        drop(cursor);
+        assert_eq!(list, [-1, 0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_cursor_mut_remove() {
+        let mut list: List<i32> = (0..6).collect();
+        let mut cursor = list.cursor_front_mut();
+        // Remove every even value in a single pass
+        while let Some(value) = cursor.current().map(|value| *value.borrow()) {
+            if value % 2 == 0 {
+                assert_eq!(cursor.remove_current(), Some(value));
+            } else {
+                cursor.move_next();
+            }
+        }
+        assert_eq!(cursor.remove_current(), None);
+        drop(cursor);
+        assert_eq!(list, [1, 3, 5]);
+
+        let mut cursor = list.cursor_back_mut();
+        assert_eq!(cursor.remove_current(), Some(5));
+        assert_eq!(cursor.index(), None);
+        cursor.move_prev();
+        assert_eq!(cursor.index(), Some(1));
+        drop(cursor);
+        assert_eq!(list, [1, 3]);
+        assert_eq!(list.back(), Some(3));
+    }
+
+    #[test]
+    fn test_cursor_mut_replace() {
+        let mut list: List<String> = list!["a".to_string(), "b".to_string()];
+        let mut cursor = list.cursor_back_mut();
+        assert_eq!(cursor.replace_current("c".to_string()), Ok("b".to_string()));
+        cursor.current().unwrap().borrow_mut().push('!');
+        cursor.move_next();
+        assert_eq!(
+            cursor.replace_current("d".to_string()),
+            Err("d".to_string())
+        );
+        drop(cursor);
+        assert_eq!(list, ["a", "c!"]);
+
+        let mut empty: List<i32> = List::new();
+        let mut cursor = empty.cursor_front_mut();
+        cursor.insert_after(1);
+        cursor.insert_before(2);
+        assert_eq!(cursor.index(), None);
+        drop(cursor);
+        assert_eq!(empty, [1, 2]);
+    }
+
+    #[test]
+    fn test_remove_handle() {
+        let mut list = List::new();
+        let handles: Vec<NodeHandle<usize>> =
+            (0..UPPER_BOUNDS).map(|i| list.push_back(i)).collect();
+        let front = list.push_front(UPPER_BOUNDS);
+        assert_eq!(list.remove_handle(&front), Ok(UPPER_BOUNDS));
+        assert_eq!(list.remove_handle(&handles[500]), Ok(500));
+        assert_eq!(
+            list.remove_handle(&handles[UPPER_BOUNDS - 1]),
+            Ok(UPPER_BOUNDS - 1)
+        );
+        assert_eq!(list.len(), UPPER_BOUNDS - 2);
+        assert_eq!(list.get(500), Some(501));
+        assert_eq!(list.back(), Some(UPPER_BOUNDS - 2));
+        assert_eq!(list.front(), Some(0));
+
+        // Removing twice does nothing, the node is gone
+        assert_eq!(list.remove_handle(&handles[500]), Err(HandleError::Removed));
+        assert_eq!(handles[500].get(), Err(HandleError::Removed));
+        assert_eq!(list.len(), UPPER_BOUNDS - 2);
+
+        let inserted = list.insert(10, 42).unwrap();
+        assert_eq!(inserted.get(), Ok(42));
+        assert_eq!(list.remove_handle(&inserted), Ok(42));
+        assert_eq!(list.get(10), Some(10));
+    }
+
+    #[test]
+    fn test_remove_handle_after_nested_appends() {
+        let mut list = List::new();
+        let handles: Vec<NodeHandle<usize>> = (0..3).map(|i| list.push_back(i)).collect();
+        // Every round forwards the owner of the first nodes once more
+        (3..UPPER_BOUNDS * 10).for_each(|i| {
+            let mut outer = List::new();
+            outer.push_back(i);
+            outer.append(&mut list);
+            list = outer;
+        });
+        assert_eq!(list.len(), UPPER_BOUNDS * 10);
+        assert_eq!(list.remove_handle(&handles[1]), Ok(1));
+        assert_eq!(list.remove_handle(&handles[0]), Ok(0));
+        assert_eq!(list.remove_handle(&handles[2]), Ok(2));
+        assert_eq!(list.back(), Some(3));
+    }
+
+    #[test]
+    fn test_owner_path_compression() {
+        let owners: Vec<Rc<Owner>> = (0..100).map(|_| Owner::new()).collect();
+        owners.windows(2).for_each(|pair| {
+            *pair[0].forward.borrow_mut() = Some(Rc::clone(&pair[1]));
+        });
+        assert!(Rc::ptr_eq(&Owner::resolve(&owners[0]), &owners[99]));
+        owners[..99].iter().for_each(|owner| {
+            let forward = owner.forward.borrow().clone().unwrap();
+            assert!(Rc::ptr_eq(&forward, &owners[99]));
+        });
+        assert!(owners[99].forward.borrow().is_none());
+    }
+
+    #[test]
+    fn test_remove_handle_foreign_list() {
+        let mut a: List<i32> = (0..5).collect();
+        let mut b: List<i32> = (0..5).collect();
+        let (_, middle) = a.iter_handles().nth(2).unwrap();
+        assert_eq!(b.remove_handle(&middle), Err(HandleError::ForeignList));
+        assert_eq!(b.len(), 5);
+        assert_eq!(a.remove_handle(&middle), Ok(2));
+        assert_eq!(a, [0, 1, 3, 4]);
+        assert_eq!(b, [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_handles_follow_moved_nodes() {
+        let mut a = List::new();
+        let first = a.push_back(1);
+        let mut b = List::new();
+        let second = b.push_back(2);
+
+        // Appending moves the nodes over without visiting them
+        a.append(&mut b);
+        assert_eq!(b.remove_handle(&second), Err(HandleError::ForeignList));
+        let third = b.push_back(3);
+        a.prepend(&mut b);
+        assert_eq!(a, [3, 1, 2]);
+
+        // Splitting hands the nodes to a new list
+        let mut tail = a.split_off(1);
+        assert_eq!(a.remove_handle(&first), Err(HandleError::ForeignList));
+        assert_eq!(tail.remove_handle(&first), Ok(1));
+        assert_eq!(tail.remove_handle(&third), Err(HandleError::ForeignList));
+        assert_eq!(a.remove_handle(&third), Ok(3));
+        assert_eq!(tail.remove_handle(&second), Ok(2));
+        assert!(a.is_empty() && tail.is_empty());
+    }
+
+    #[test]
+    fn test_handles_after_split_sides() {
+        // Both sides of a split are tracked, whichever one is the smaller one
+        let mut list = List::new();
+        let handles: Vec<NodeHandle<usize>> = (0..100).map(|i| list.push_back(i)).collect();
+        let mut long_tail = list.split_off(10);
+        let mut short_tail = long_tail.split_off(80);
+        assert_eq!(
+            (list.len(), long_tail.len(), short_tail.len()),
+            (10, 80, 10)
+        );
+        (0..100).for_each(|i| {
+            let lists = [&list, &long_tail, &short_tail];
+            let owner = if i < 10 {
+                0
+            } else if i < 90 {
+                1
+            } else {
+                2
+            };
+            (0..3).for_each(|l| {
+                assert_eq!(
+                    lists[l].owns(&handles[i].node.upgrade().unwrap()).is_ok(),
+                    l == owner
+                )
+            });
+        });
+        assert_eq!(long_tail.remove_handle(&handles[50]), Ok(50));
+        assert_eq!(short_tail.remove_handle(&handles[95]), Ok(95));
+        assert_eq!(list.remove_handle(&handles[5]), Ok(5));
+
+        let mut merged = List::concat([list, long_tail, short_tail]);
+        assert_eq!(merged.len(), 97);
+        assert_eq!(merged.remove_handle(&handles[99]), Ok(99));
+    }
+
+    #[test]
+    fn test_remove_handle_partition() {
+        let mut list = List::new();
+        let handles: Vec<NodeHandle<i32>> = (0..10).map(|i| list.push_back(i)).collect();
+        let (mut even, mut odd) = list.partition(|v| v % 2 == 0);
+        assert_eq!(
+            odd.remove_handle(&handles[4]),
+            Err(HandleError::ForeignList)
+        );
+        assert_eq!(even.remove_handle(&handles[4]), Ok(4));
+        assert_eq!(odd.remove_handle(&handles[7]), Ok(7));
+        assert_eq!(even, [0, 2, 6, 8]);
+        assert_eq!(odd, [1, 3, 5, 9]);
+    }
+
+    #[test]
+    fn test_insert_at_handle() {
+        let mut list = List::new();
+        let middle = list.push_back(2);
+        let after = list.insert_after(&middle, 3).unwrap();
+        let before = list.insert_before(&middle, 1).unwrap();
+        list.insert_after(&after, 4).unwrap();
+        list.insert_before(&before, 0).unwrap();
+        assert_eq!(list, [0, 1, 2, 3, 4]);
+        assert_eq!(list.front(), Some(0));
+        assert_eq!(list.back(), Some(4));
+        assert_eq!(list.len(), 5);
+
+        // Handles stay usable while values are inserted around them
+        (10..20).for_each(|i| {
+            list.insert_after(&middle, i).unwrap();
+        });
+        assert_eq!(list.get(2), Some(2));
+        assert_eq!(list.get(3), Some(19));
+        assert_eq!(list.get(12), Some(10));
+        assert_eq!(list.get(13), Some(3));
+        assert_eq!(list.len(), 15);
+    }
+
+    #[test]
+    fn test_insert_at_invalid_handle() {
+        let mut list: List<i32> = (0..3).collect();
+        let mut other = List::new();
+        let foreign = other.push_back(9);
+        assert_eq!(
+            list.insert_after(&foreign, 5).err(),
+            Some((HandleError::ForeignList, 5))
+        );
+        assert_eq!(
+            list.insert_before(&foreign, 6).err(),
+            Some((HandleError::ForeignList, 6))
+        );
+
+        let removed = list.push_back(3);
+        list.remove_handle(&removed).unwrap();
+        assert_eq!(
+            list.insert_after(&removed, 7).err(),
+            Some((HandleError::Removed, 7))
+        );
+        assert_eq!(list, [0, 1, 2]);
+        assert_eq!(other, [9]);
+    }
+
+    #[test]
+    fn test_move_to_ends() {
+        let mut list = List::new();
+        let handles: Vec<NodeHandle<i32>> = (0..5).map(|i| list.push_back(i)).collect();
+        assert!(list.move_to_front(&handles[2]).is_ok());
+        assert_eq!(list, [2, 0, 1, 3, 4]);
+        assert!(list.move_to_back(&handles[0]).is_ok());
+        assert_eq!(list, [2, 1, 3, 4, 0]);
+        assert!(list.move_to_front(&handles[2]).is_ok());
+        assert!(list.move_to_back(&handles[0]).is_ok());
+        assert!(list.move_to_front(&handles[0]).is_ok());
+        assert_eq!(list, [0, 2, 1, 3, 4]);
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.back(), Some(4));
+        list.reverse();
+        assert_eq!(list.iter().rev().collect::<Vec<_>>(), vec![0, 2, 1, 3, 4]);
+
+        let single = handles[4].clone();
+        let mut other = List::new();
+        other.push_back(9);
+        assert_eq!(other.move_to_front(&single), Err(HandleError::ForeignList));
+        assert_eq!(list.remove_handle(&single), Ok(4));
+        assert_eq!(list.move_to_back(&single), Err(HandleError::Removed));
+        assert_eq!(list, [3, 1, 2, 0]);
+    }
+
+    #[test]
+    fn test_move_to_front_lru() {
+        // Least recently used values end up at the back, where they are evicted from
+        let capacity = 3;
+        let mut order = List::new();
+        let mut cached: std::collections::HashMap<char, NodeHandle<char>> = Default::default();
+        for key in "abcabdaec".chars() {
+            match cached.get(&key) {
+                Some(handle) => {
+                    order.move_to_front(handle).unwrap();
+                }
+                None => {
+                    if order.len() == capacity {
+                        let evicted = order.pop_back().unwrap();
+                        cached.remove(&evicted);
+                    }
+                    cached.insert(key, order.push_front(key));
+                }
+            }
+        }
+        assert_eq!(order, ['c', 'e', 'a']);
+    }
+
+    #[test]
+    fn test_cursor_split() {
+        let mut list: List<i32> = (0..10).collect();
+        let mut cursor = list.cursor_front_mut();
+        (0..4).for_each(|_| cursor.move_next());
+        let after = cursor.split_after();
+        assert_eq!(cursor.index(), Some(4));
+        let before = cursor.split_before();
+        assert_eq!(cursor.index(), Some(0));
+        assert_eq!(*cursor.current().unwrap().borrow(), 4);
+
+        // Nothing left on either side of the only value
+        assert!(cursor.split_after().is_empty());
+        assert!(cursor.split_before().is_empty());
+        assert_eq!(cursor.index(), Some(0));
+        drop(cursor);
+        assert_eq!(list, [4]);
+        assert_eq!(before, [0, 1, 2, 3]);
+        assert_eq!(after, [5, 6, 7, 8, 9]);
+        assert_eq!(before.back(), Some(3));
+        assert_eq!(after.front(), Some(5));
+    }
+
+    #[test]
+    fn test_cursor_split_ghost() {
+        let mut list: List<i32> = (0..5).collect();
+        let mut cursor = list.cursor_back_mut();
+        cursor.move_next();
+        let everything = cursor.split_after();
+        assert_eq!(everything, [0, 1, 2, 3, 4]);
+        assert_eq!(cursor.index(), None);
+        cursor.insert_before(7);
+        drop(cursor);
+        assert_eq!(list, [7]);
+
+        let mut list: List<i32> = (0..5).collect();
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_prev();
+        assert_eq!(cursor.split_before(), [0, 1, 2, 3, 4]);
+        drop(cursor);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_cursor_split_handles() {
+        let mut list = List::new();
+        let handles: Vec<NodeHandle<usize>> =
+            (0..UPPER_BOUNDS).map(|i| list.push_back(i)).collect();
+        let mut cursor = list.cursor_front_mut();
+        (0..10).for_each(|_| cursor.move_next());
+        let mut after = cursor.split_after();
+        let mut before = cursor.split_before();
+        drop(cursor);
+        assert_eq!(after.len(), UPPER_BOUNDS - 11);
+        assert_eq!(before.len(), 10);
+        assert_eq!(
+            list.remove_handle(&handles[9]),
+            Err(HandleError::ForeignList)
+        );
+        assert_eq!(before.remove_handle(&handles[9]), Ok(9));
+        assert_eq!(
+            before.remove_handle(&handles[11]),
+            Err(HandleError::ForeignList)
+        );
+        assert_eq!(after.remove_handle(&handles[11]), Ok(11));
+        assert_eq!(list.remove_handle(&handles[10]), Ok(10));
+    }
+
+    #[test]
+    fn test_cursor_splice() {
+        let mut list: List<i32> = list![0, 5, 9];
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        cursor.splice_before(list![1, 2]);
+        assert_eq!(cursor.index(), Some(3));
+        cursor.splice_after(list![6, 7, 8]);
+        assert_eq!(cursor.index(), Some(3));
+        cursor.splice_before(list![3, 4]);
+        cursor.splice_after(List::new());
+        cursor.splice_before(List::new());
+        assert_eq!(cursor.index(), Some(5));
+        assert_eq!(*cursor.current().unwrap().borrow(), 5);
+        drop(cursor);
+        assert_eq!(list, (0..10).collect::<Vec<i32>>());
+        assert_eq!(list.len(), 10);
+        assert_eq!(
+            list.iter().rev().collect::<Vec<i32>>(),
+            (0..10).rev().collect::<Vec<i32>>()
+        );
+    }
+
+    #[test]
+    fn test_cursor_splice_ends() {
+        let mut list: List<i32> = list![2];
+        let mut cursor = list.cursor_front_mut();
+        cursor.splice_before(list![0, 1]);
+        cursor.splice_after(list![3, 4]);
+        assert_eq!(cursor.index(), Some(2));
+        cursor.move_prev();
+        cursor.move_prev();
+        cursor.move_prev();
+        assert_eq!(cursor.index(), None);
+        cursor.splice_after(list![-2, -1]);
+        cursor.splice_before(list![5, 6]);
+        assert_eq!(cursor.index(), None);
+        cursor.move_prev();
+        assert_eq!(cursor.index(), Some(8));
+        drop(cursor);
+        assert_eq!(list, [-2, -1, 0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(list.front(), Some(-2));
+        assert_eq!(list.back(), Some(6));
+
+        let mut empty: List<i32> = List::new();
+        let mut cursor = empty.cursor_front_mut();
+        cursor.splice_before(list![1, 2]);
+        drop(cursor);
+        assert_eq!(empty, [1, 2]);
+    }
+
+    #[test]
+    fn test_cursor_splice_handles() {
+        let mut list: List<i32> = list![0, 3];
+        let mut other = List::new();
+        let handles = [other.push_back(1), other.push_back(2)];
+        let mut cursor = list.cursor_back_mut();
+        cursor.splice_before(other);
+        drop(cursor);
+        assert_eq!(list.remove_handle(&handles[0]), Ok(1));
+        assert!(list.move_to_back(&handles[1]).is_ok());
+        assert_eq!(list, [0, 3, 2]);
+    }
+
+    #[test]
+    fn test_cursor_seek() {
+        let list: List<i32> = (0..20).collect();
+        let mut cursor = list.cursor_front();
+        assert!(cursor.seek_forward(|v| v % 7 == 6));
+        assert_eq!(cursor.index(), Some(6));
+        // The current value is checked first, so seeking again stays put
+        assert!(cursor.seek_forward(|v| v % 7 == 6));
+        assert_eq!(cursor.index(), Some(6));
+        cursor.move_next();
+        assert!(cursor.seek_forward(|v| v % 7 == 6));
+        assert_eq!(cursor.index(), Some(13));
+
+        assert!(cursor.seek_backward(|v| *v < 3));
+        assert_eq!(*cursor.current().unwrap().borrow(), 2);
+        assert!(!cursor.seek_backward(|v| *v > 100));
+        assert_eq!(cursor.index(), None);
+        assert!(!cursor.seek_forward(|_| true));
+
+        let mut cursor = list.cursor_back();
+        assert!(!cursor.seek_forward(|v| *v == 0));
+        assert!(cursor.current().is_none());
+    }
+
+    #[test]
+    fn test_cursor_mut_as_cursor() {
+        let mut list: List<i32> = (0..5).collect();
+        let mut cursor = list.cursor_front_mut();
+        cursor.seek_forward(|v| *v == 3);
+        let mut view = cursor.as_cursor();
+        assert_eq!(view.index(), Some(3));
+        view.move_next();
+        view.move_next();
+        assert_eq!(view.index(), None);
+        view.move_prev();
+        assert!(view.seek_backward(|v| *v == 1));
+        assert_eq!(view.index(), Some(1));
+        drop(view);
+        // Moving the view leaves the cursor itself in place
+        assert_eq!(cursor.index(), Some(3));
+        cursor.remove_current();
+        assert_eq!(cursor.as_cursor().index(), Some(3));
+        drop(cursor);
+        assert_eq!(list, [0, 1, 2, 4]);
+    }
+
+    #[test]
+    fn test_cursor_mut_seek_and_edit() {
+        // Put a marker after every multiple of 4, using a single pass
+        let mut list: List<i32> = (1..=12).collect();
+        let mut cursor = list.cursor_front_mut();
+        while cursor.seek_forward(|v| v % 4 == 0) {
+            cursor.insert_after(-1);
+            cursor.move_next();
+            cursor.move_next();
+        }
+        drop(cursor);
+        assert_eq!(list, [1, 2, 3, 4, -1, 5, 6, 7, 8, -1, 9, 10, 11, 12, -1]);
+
+        let mut cursor = list.cursor_back_mut();
+        while cursor.seek_backward(|v| *v < 0) {
+            cursor.remove_current();
+            cursor.move_prev();
+        }
+        drop(cursor);
+        assert_eq!(list, (1..=12).collect::<Vec<i32>>());
+    }
+
+    #[test]
+    fn test_cursor_index_tracking() {
+        // Random edits through a single cursor, checked against a Vec and a position into it
+        let mut list: List<u32> = (0..20).collect();
+        let mut model: Vec<u32> = (0..20).collect();
+        let mut position = 0;
+        let mut cursor = list.cursor_front_mut();
+        let mut seed: u32 = 12345;
+        let mut next_value = 100;
+        for _ in 0..2000 {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            let ghost = position == model.len();
+            next_value += 1;
+            match (seed >> 16) % 11 {
+                0 | 1 => {
+                    cursor.move_next();
+                    position = if ghost { 0 } else { position + 1 };
+                }
+                2 | 3 => {
+                    cursor.move_prev();
+                    position = match (ghost, position) {
+                        (true, _) => model.len().saturating_sub(1),
+                        (false, 0) => model.len(),
+                        (false, _) => position - 1,
+                    };
+                }
+                4 => {
+                    cursor.insert_before(next_value);
+                    model.insert(position, next_value);
+                    position += 1;
+                }
+                5 => {
+                    cursor.insert_after(next_value);
+                    if ghost {
+                        model.insert(0, next_value);
+                        position = model.len();
+                    } else {
+                        model.insert(position + 1, next_value);
+                    }
+                }
+                6 | 7 => {
+                    let expected = (!ghost).then(|| model.remove(position));
+                    assert_eq!(cursor.remove_current(), expected);
+                }
+                8 => {
+                    cursor.splice_before(list![next_value, next_value + 1]);
+                    model.splice(position..position, [next_value, next_value + 1]);
+                    position += 2;
+                }
+                9 => {
+                    cursor.splice_after(list![next_value]);
+                    if ghost {
+                        model.insert(0, next_value);
+                        position = model.len();
+                    } else {
+                        model.insert(position + 1, next_value);
+                    }
+                }
+                _ => {
+                    // Splitting shrinks the list, so only do it once it has grown a bit
+                    if model.len() > 30 && !ghost {
+                        if (seed >> 8) & 1 == 0 {
+                            assert_eq!(cursor.split_after(), model.split_off(position + 1));
+                        } else {
+                            let before: Vec<u32> = model.drain(..position).collect();
+                            assert_eq!(cursor.split_before(), before);
+                            position = 0;
+                        }
+                    }
+                }
+            }
+
+            let ghost = position == model.len();
+            assert_eq!(cursor.index(), (!ghost).then_some(position));
+            assert_eq!(
+                cursor.current().map(|v| *v.borrow()),
+                model.get(position).copied()
+            );
+        }
+        drop(cursor);
+        assert_eq!(list, model);
+    }
+
+    #[test]
+    fn test_stale_handles() {
+        let mut list = List::new();
+        let handles: Vec<NodeHandle<i32>> = (0..10).map(|i| list.push_back(i)).collect();
+        list.pop_front();
+        list.pop_back();
+        list.retain(|v| *v != 4);
+        list.truncate(6);
+        assert_eq!(list, [1, 2, 3, 5, 6, 7]);
+        [0, 4, 8, 9].iter().for_each(|&i| {
+            assert_eq!(list.remove_handle(&handles[i]), Err(HandleError::Removed));
+            assert_eq!(list.move_to_back(&handles[i]), Err(HandleError::Removed));
+            assert_eq!(handles[i].get(), Err(HandleError::Removed));
+        });
+
+        // Nodes that are still alive in a drained range belong to that range now
+        let drained = list.drain_range(0..2);
+        assert_eq!(
+            list.remove_handle(&handles[1]),
+            Err(HandleError::ForeignList)
+        );
+        assert_eq!(handles[1].get(), Ok(1));
+        drop(drained);
+        assert_eq!(list.remove_handle(&handles[1]), Err(HandleError::Removed));
+
+        list.clear();
+        assert!(handles.iter().all(|h| h.get() == Err(HandleError::Removed)));
+        let fresh = list.push_back(20);
+        assert!(handles.iter().all(|h| !h.ptr_eq(&fresh)));
+        assert_eq!(list.remove_handle(&handles[5]), Err(HandleError::Removed));
+        assert_eq!(list, [20]);
+
+        let dropped = {
+            let mut temporary = List::new();
+            temporary.push_back(1)
+        };
+        assert_eq!(dropped.get(), Err(HandleError::Removed));
+        assert_eq!(
+            list.insert_after(&dropped, 2).err(),
+            Some((HandleError::Removed, 2))
+        );
+    }
+
+    #[test]
+    fn test_handle_borrowed() {
+        let mut list = List::new();
+        let handle = list.push_back("a".to_string());
+        let guard = list.get_mut(0).unwrap();
+        let borrow = guard.borrow_mut();
+        assert_eq!(handle.get(), Err(HandleError::Borrowed));
+        drop(borrow);
+        assert_eq!(handle.get(), Ok("a".to_string()));
+    }
+
+    #[test]
+    fn test_handle_value_moved_out() {
+        let mut list: List<String> = List::new();
+        let handle = list.push_back("a".to_string());
+        list.push_back("b".to_string());
+        {
+            let mut values = list.iter_mut();
+            let mut first = values.next().unwrap();
+            first.push('!');
+            assert_eq!(handle.get(), Err(HandleError::Borrowed));
+        }
+        assert_eq!(handle.get(), Ok("a!".to_string()));
+
+        list.map_in_place(|value| {
+            if value == "a!" {
+                assert_eq!(handle.get(), Err(HandleError::Borrowed));
+            } else {
+                assert_eq!(handle.get(), Ok("a!?".to_string()));
+            }
+            value + "?"
+        });
+        assert_eq!(handle.get(), Ok("a!?".to_string()));
+        assert_eq!(list, ["a!?", "b?"]);
+    }
+
+    #[test]
+    fn test_cursor_at_borrowed() {
+        let mut list: List<i32> = List::new();
+        let handles: Vec<NodeHandle<i32>> = (0..5).map(|i| list.push_back(i)).collect();
+        {
+            let guard = list.get_ref(0).unwrap();
+            let _reading = guard.borrow();
+            assert_eq!(list.cursor_at(&handles[0]).unwrap().index(), Some(0));
+        }
+        {
+            let guard = list.get_mut(1).unwrap();
+            let _writing = guard.borrow_mut();
+            assert_eq!(
+                list.cursor_at(&handles[1]).err(),
+                Some(HandleError::Borrowed)
+            );
+            // Walking back to the head passes the node being written to
+            assert_eq!(
+                list.cursor_at(&handles[2]).err(),
+                Some(HandleError::Borrowed)
+            );
+            assert_eq!(list.cursor_at(&handles[4]).unwrap().index(), Some(4));
+        }
+        assert_eq!(list.cursor_at(&handles[1]).unwrap().index(), Some(1));
+    }
+
+    #[test]
+    fn test_weak_handle_upgrade_borrowed() {
+        let mut list: List<i32> = List::new();
+        let observer = list.push_back(1).downgrade();
+        let mut other = List::new();
+        other.push_back(0);
+        // Leaves the node forwarded, so upgrading would shorten the owner chain
+        other.append(&mut list);
+        {
+            let current = other.cursor_back().current().unwrap();
+            let _reading = current.borrow();
+            assert!(observer.upgrade(&other).is_some());
+            assert!(observer.upgrade(&list).is_none());
+        }
+        {
+            let guard = other.get_mut(1).unwrap();
+            let _writing = guard.borrow_mut();
+            assert!(observer.upgrade(&other).is_none());
+        }
+        assert_eq!(observer.upgrade(&other).unwrap().get(), Ok(1));
+    }
+
+    #[test]
+    fn test_handle_error_display() {
+        assert_eq!(
+            HandleError::Removed.to_string(),
+            "node has been removed from its list"
+        );
+        assert_eq!(
+            HandleError::ForeignList.to_string(),
+            "node belongs to a different list"
+        );
+        let error: Box<dyn std::error::Error> = Box::new(HandleError::Borrowed);
+        assert_eq!(error.to_string(), "node value is currently borrowed");
+    }
+
+    #[test]
+    fn test_weak_handle() {
+        let mut list: List<Rc<i32>> = List::new();
+        let value = Rc::new(5);
+        let handle = list.push_back(Rc::clone(&value));
+        let observer = handle.downgrade();
+        drop(handle);
+
+        // Upgrading only works against the list the node is in
+        let other: List<Rc<i32>> = List::new();
+        assert!(observer.upgrade(&other).is_none());
+        let upgraded = observer.upgrade(&list).unwrap();
+        assert_eq!(upgraded.get(), Ok(Rc::clone(&value)));
+        assert!(!observer.is_removed());
+
+        // Removing the node frees it and its value right away
+        assert_eq!(Rc::strong_count(&value), 2);
+        list.remove_handle(&upgraded).unwrap();
+        drop(upgraded);
+        assert_eq!(Rc::strong_count(&value), 1);
+        assert!(observer.is_removed());
+        assert!(observer.upgrade(&list).is_none());
+        assert!(observer.clone().upgrade(&list).is_none());
+    }
+
+    #[test]
+    fn test_weak_handle_moved_node() {
+        let mut list: List<i32> = (0..10).collect();
+        let (_, handle) = list.iter_handles().nth(7).unwrap();
+        let observer = handle.downgrade();
+        let tail = list.split_off(5);
+        assert!(observer.upgrade(&list).is_none());
+        assert!(!observer.is_removed());
+        assert_eq!(observer.upgrade(&tail).unwrap().get(), Ok(7));
+        drop(tail);
+        assert!(observer.is_removed());
+    }
+
+    #[test]
+    fn test_cursor_at() {
+        let mut list = List::new();
+        let handles: Vec<NodeHandle<usize>> =
+            (0..UPPER_BOUNDS).map(|i| list.push_back(i)).collect();
+        [0, 1, 499, 500, 501, UPPER_BOUNDS - 2, UPPER_BOUNDS - 1]
+            .iter()
+            .for_each(|&i| {
+                let cursor = list.cursor_at(&handles[i]).unwrap();
+                assert_eq!(cursor.index(), Some(i));
+                assert_eq!(*cursor.current().unwrap().borrow(), i);
+            });
+
+        let cursor = list.cursor_back();
+        let handle = cursor.handle().unwrap();
+        drop(cursor);
+        assert!(handle.ptr_eq(&handles[UPPER_BOUNDS - 1]));
+        let mut cursor = list.cursor_at_mut(&handles[10]).unwrap();
+        cursor.move_prev();
+        assert_eq!(cursor.remove_current(), Some(9));
+        assert_eq!(cursor.index(), Some(9));
+        drop(cursor);
+        assert_eq!(
+            list.cursor_at(&handles[9]).err(),
+            Some(HandleError::Removed)
+        );
+
+        let other: List<usize> = list![1];
+        assert_eq!(
+            other.cursor_at(&handles[0]).err(),
+            Some(HandleError::ForeignList)
+        );
+    }
+
+    #[test]
+    fn test_multiple_cursors() {
+        // Read-only cursors can be used side by side
+        let list: List<i32> = (0..10).collect();
+        let mut forward = list.cursor_front();
+        let mut backward = list.cursor_back();
+        while forward.index() < backward.index() {
+            assert_eq!(
+                *forward.current().unwrap().borrow() + *backward.current().unwrap().borrow(),
+                9
+            );
+            forward.move_next();
+            backward.move_prev();
+        }
+        assert_eq!((forward.index(), backward.index()), (Some(5), Some(4)));
+    }
+
+    #[test]
+    fn test_interleaved_cursor_edits() {
+        // Editing cursors take turns, each one is parked as a handle while another one edits
+        let mut list: List<i32> = (0..10).collect();
+        let mut first = list.cursor_front_mut();
+        first.move_next();
+        let first_position = first.handle().unwrap();
+        drop(first);
+        let mut second = list.cursor_back_mut();
+        second.move_prev();
+        let second_position = second.handle().unwrap();
+        drop(second);
+
+        // The first cursor inserts in front of the second one, which keeps its node
+        let mut first = list.cursor_at_mut(&first_position).unwrap();
+        first.insert_after(100);
+        first.insert_before(-100);
+        let first_position = first.handle().unwrap();
+        drop(first);
+        let second = list.cursor_at_mut(&second_position).unwrap();
+        assert_eq!(second.index(), Some(10));
+        drop(second);
+
+        // The second cursor removes the node the first one is parked on
+        let mut second = list.cursor_at_mut(&second_position).unwrap();
+        while second.seek_backward(|v| *v == 1) {
+            second.remove_current();
+        }
+        drop(second);
+        assert_eq!(
+            list.cursor_at_mut(&first_position).err(),
+            Some(HandleError::Removed)
+        );
+        assert_eq!(list.cursor_at(&second_position).unwrap().index(), Some(9));
+
+        // A cursor parked on a node that moved to another list can't be used on this one
+        let tail = list.split_off(8);
+        assert_eq!(
+            list.cursor_at_mut(&second_position).err(),
+            Some(HandleError::ForeignList)
+        );
+        assert_eq!(tail.cursor_at(&second_position).unwrap().index(), Some(1));
+        assert_eq!(list, [0, -100, 100, 2, 3, 4, 5, 6]);
+        assert_eq!(tail, [7, 8, 9]);
+    }
+
+    #[test]
+    fn test_entry() {
+        let mut list: List<i32> = (0..5).collect();
+        list.entry(2)
+            .unwrap()
+            .and_modify(|v| *v *= 10)
+            .or_insert(-1);
+        list.entry(5)
+            .unwrap()
+            .and_modify(|v| *v *= 10)
+            .or_insert(-1);
+        assert_eq!(list, [0, 1, 20, 3, 4, -1]);
+
+        *list
+            .entry(0)
+            .unwrap()
+            .or_insert_with(|| unreachable!())
+            .borrow_mut() += 7;
+        assert_eq!(*list.entry(6).unwrap().or_insert_with(|| 42).borrow(), 42);
+        assert_eq!(*list.entry(7).unwrap().or_default().borrow(), 0);
+        assert_eq!(list, [7, 1, 20, 3, 4, -1, 42, 0]);
+
+        // Past the end there is nowhere to insert, just like with insert
+        assert!(list.entry(9).is_none());
+        assert!(list.entry(100).is_none());
+        assert_eq!(list.len(), 8);
+    }
+
+    #[test]
+    fn test_entry_variants() {
+        let mut list: List<String> = list!["a".to_string(), "b".to_string()];
+        match list.entry(0) {
+            Some(Entry::Occupied(entry)) => assert_eq!(entry.remove(), "a"),
+            _ => unreachable!(),
+        }
+        match list.entry(1) {
+            Some(Entry::Vacant(entry)) => entry.insert("c".to_string()).borrow_mut().push('!'),
+            _ => unreachable!(),
+        }
+        if let Some(Entry::Occupied(entry)) = list.entry(0) {
+            entry.into_mut().borrow_mut().push('?');
+        }
+        assert_eq!(list, ["b?", "c!"]);
+
+        // An entry that is no longer used doesn't keep its node from being removed
+        let _unused = list.entry(0);
+        assert_eq!(list.pop_front(), Some("b?".to_string()));
+
+        let mut empty: List<i32> = List::new();
+        empty
+            .entry(0)
+            .unwrap()
+            .and_modify(|_| unreachable!())
+            .or_insert(1);
+        assert_eq!(empty, [1]);
+        assert!(List::<i32>::new().entry(1).is_none());
     }
 }